@@ -0,0 +1,180 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse::{Parse, ParseStream},
+    Attribute, DeriveInput, Error, Ident, LitBool, LitStr, Path, Result, Token, Visibility,
+};
+
+use crate::{flags::parse_flags, util::AsOption};
+
+/// Where a context menu command shows up
+#[derive(Copy, Clone)]
+pub enum ContextMenuKind {
+    Message,
+    User,
+}
+
+impl ContextMenuKind {
+    fn static_suffix(self) -> &'static str {
+        match self {
+            Self::Message => "MSG",
+            Self::User => "USER",
+        }
+    }
+
+    fn fn_prefix(self) -> &'static str {
+        match self {
+            Self::Message => "msg",
+            Self::User => "user",
+        }
+    }
+
+    fn struct_path(self) -> TokenStream {
+        match self {
+            Self::Message => quote!(crate::core::commands::slash::MessageCommand),
+            Self::User => quote!(crate::core::commands::slash::UserCommand),
+        }
+    }
+
+    /// Name of the variant of both `CommandType` and `InteractionCommand`
+    fn variant(self) -> TokenStream {
+        match self {
+            Self::Message => quote!(Message),
+            Self::User => quote!(User),
+        }
+    }
+}
+
+pub fn derive(input: DeriveInput, kind: ContextMenuKind) -> Result<TokenStream> {
+    match input.vis {
+        Visibility::Public(_) => {}
+        _ => return Err(Error::new(input.ident.span(), "type must be pub")),
+    }
+
+    let name = input.ident;
+    let name_str = name.to_string();
+    let static_name = format_ident!("{}_{}", name_str.to_uppercase(), kind.static_suffix());
+    let cmd_fn = format_ident!("{}_{}", kind.fn_prefix(), name_str.to_lowercase());
+    let exec = format_ident!("{cmd_fn}__");
+    let entry = format_ident!("{static_name}_ENTRY");
+    let flags = parse_flags(&input.attrs)?.into_tokens();
+    let path = kind.struct_path();
+    let variant = kind.variant();
+
+    let CommandAttr {
+        name: command_name,
+        name_localizations,
+        default_permissions,
+        dm_permission,
+    } = parse_command(&input.attrs, name.span())?;
+
+    let name_localizations = AsOption(name_localizations.map(|path| quote!(#path())));
+    let default_permissions = AsOption(default_permissions.map(|path| quote!(#path())));
+    let dm_permission = AsOption(dm_permission);
+
+    let tokens = quote! {
+        pub static #static_name: #path = #path {
+            create: #name::create_command,
+            exec: #exec,
+            flags: #flags,
+            name: #command_name,
+        };
+
+        #[linkme::distributed_slice(crate::core::commands::slash::COMMAND_LIST)]
+        static #entry: crate::core::commands::slash::InteractionCommand =
+            crate::core::commands::slash::InteractionCommand::#variant(&#static_name);
+
+        impl #name {
+            #[allow(deprecated)]
+            pub fn create_command() -> twilight_model::application::command::Command {
+                twilight_model::application::command::Command {
+                    application_id: None,
+                    default_member_permissions: #default_permissions,
+                    description: String::new(),
+                    description_localizations: None,
+                    dm_permission: #dm_permission,
+                    guild_id: None,
+                    id: None,
+                    kind: twilight_model::application::command::CommandType::#variant,
+                    name: #command_name.to_owned(),
+                    name_localizations: #name_localizations,
+                    nsfw: None,
+                    options: Vec::new(),
+                    version: twilight_model::id::Id::new(1),
+                    contexts: None,
+                    integration_types: None,
+                }
+            }
+        }
+
+        pub fn #exec(
+            ctx: std::sync::Arc<crate::core::Context>,
+            command: crate::util::interaction::InteractionCommand,
+        ) -> crate::core::commands::slash::CommandResult {
+            Box::pin(#cmd_fn(ctx, command))
+        }
+    };
+
+    Ok(tokens)
+}
+
+struct CommandAttr {
+    name: LitStr,
+    name_localizations: Option<Path>,
+    default_permissions: Option<Path>,
+    dm_permission: Option<LitBool>,
+}
+
+fn parse_command(attrs: &[Attribute], span: Span) -> Result<CommandAttr> {
+    let attr = attrs
+        .iter()
+        .find(|attr| attr.path.is_ident("command"))
+        .ok_or_else(|| Error::new(span, r#"missing #[command(name = "...")]"#))?;
+
+    attr.parse_args()
+}
+
+impl Parse for CommandAttr {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut name = None;
+        let mut name_localizations = None;
+        let mut default_permissions = None;
+        let mut dm_permission = None;
+
+        loop {
+            let key: Ident = input.parse()?;
+            input.parse::<Token![=]>()?;
+
+            match key.to_string().as_str() {
+                "name" => name = Some(input.parse()?),
+                "name_localizations" => {
+                    name_localizations = Some(input.parse::<LitStr>()?.parse()?)
+                }
+                "default_permissions" => {
+                    default_permissions = Some(input.parse::<LitStr>()?.parse()?)
+                }
+                "dm_permission" => dm_permission = Some(input.parse()?),
+                _ => {
+                    let message = r#"expected "name", "name_localizations", "default_permissions", or "dm_permission""#;
+
+                    return Err(Error::new(key.span(), message));
+                }
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            input.parse::<Token![,]>()?;
+        }
+
+        let name = name.ok_or_else(|| input.error(r#"missing "name""#))?;
+
+        Ok(Self {
+            name,
+            name_localizations,
+            default_permissions,
+            dm_permission,
+        })
+    }
+}
@@ -1,15 +1,27 @@
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
+use crate::context_menu::ContextMenuKind;
+
+mod context_menu;
 mod flags;
 mod slash;
 mod util;
 
-/// Create a static SlashCommand `{uppercased_name}_SLASH`.
+/// Create a static SlashCommand `{uppercased_name}_SLASH` and register it in `COMMAND_LIST`.
 ///
 /// Make sure there is a function in scope with the signature
 /// `async fn slash_{lowercased_name}(Arc<Context>, Box<ApplicationCommand>) -> BotResult<()>`
-#[proc_macro_derive(SlashCommand, attributes(flags))]
+///
+/// For enums of subcommands, the function is generated instead. It parses the options and calls
+/// `async fn slash_{lowercased_name}_{lowercased_variant}(Arc<Context>, InteractionCommand, Variant)`
+/// of the invoked subcommand, or tells the user if the options were invalid.
+/// Variants of subcommand groups list their subcommands through `#[subcommands(Add, Remove)]`
+/// and call `slash_{lowercased_name}_{lowercased_variant}_{lowercased_subcommand}` instead.
+///
+/// With `#[autocomplete]`, autocomplete interactions of the command are handled by a function
+/// `async fn autocomplete_{lowercased_name}(Arc<Context>, AutocompleteInput) -> Result<Vec<CommandOptionChoice>>`
+#[proc_macro_derive(SlashCommand, attributes(autocomplete, flags, subcommands))]
 pub fn slash_command(input: TokenStream) -> TokenStream {
     let derive_input = parse_macro_input!(input as DeriveInput);
 
@@ -18,3 +30,37 @@ pub fn slash_command(input: TokenStream) -> TokenStream {
         Err(err) => err.to_compile_error().into(),
     }
 }
+
+/// Create a static MessageCommand `{uppercased_name}_MSG` and register it in `COMMAND_LIST`.
+///
+/// Make sure there is a function in scope with the signature
+/// `async fn msg_{lowercased_name}(Arc<Context>, InteractionCommand) -> Result<()>`
+///
+/// The command is defined through `#[command(name = "...")]` which optionally takes
+/// `dm_permission = bool` and the paths of functions for `name_localizations = "..."`
+/// and `default_permissions = "..."`.
+#[proc_macro_derive(MessageCommand, attributes(command, flags))]
+pub fn message_command(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    match context_menu::derive(derive_input, ContextMenuKind::Message) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
+
+/// Create a static UserCommand `{uppercased_name}_USER` and register it in `COMMAND_LIST`.
+///
+/// Make sure there is a function in scope with the signature
+/// `async fn user_{lowercased_name}(Arc<Context>, InteractionCommand) -> Result<()>`
+///
+/// Takes the same attributes as `MessageCommand`.
+#[proc_macro_derive(UserCommand, attributes(command, flags))]
+pub fn user_command(input: TokenStream) -> TokenStream {
+    let derive_input = parse_macro_input!(input as DeriveInput);
+
+    match context_menu::derive(derive_input, ContextMenuKind::User) {
+        Ok(result) => result.into(),
+        Err(err) => err.to_compile_error().into(),
+    }
+}
@@ -0,0 +1,92 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, DataEnum, Error, Fields, Ident, Result};
+
+use crate::util::Parenthesised;
+
+/// Generate `slash_{lowercased_name}` which parses the options and calls
+/// `slash_{lowercased_name}_{lowercased_variant}` with the arguments of the invoked subcommand.
+///
+/// Variants of subcommand groups list the variants of their enum through
+/// `#[subcommands(Add, Remove)]` and call `slash_{lowercased_name}_{lowercased_variant}_{lowercased_subcommand}`.
+pub fn subcommands(
+    name: &Ident,
+    slash_cmd: &Ident,
+    static_name: &Ident,
+    data: &DataEnum,
+) -> Result<TokenStream> {
+    let mut arms = Vec::with_capacity(data.variants.len());
+
+    for variant in data.variants.iter() {
+        let group = match variant.fields {
+            Fields::Unnamed(ref fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+            _ => {
+                let message = "subcommand variants must have exactly one unnamed field";
+
+                return Err(Error::new(variant.ident.span(), message));
+            }
+        };
+
+        let variant_name = &variant.ident;
+        let handler = format_ident!("{slash_cmd}_{}", variant_name.to_string().to_lowercase());
+
+        match parse_subcommands(&variant.attrs)? {
+            Some(subcommands) => {
+                for subcommand in subcommands {
+                    let handler =
+                        format_ident!("{handler}_{}", subcommand.to_string().to_lowercase());
+
+                    arms.push(quote! {
+                        #name::#variant_name(#group::#subcommand(args)) => #handler(ctx, command, args).await,
+                    });
+                }
+            }
+            None => arms.push(quote! {
+                #name::#variant_name(args) => #handler(ctx, command, args).await,
+            }),
+        }
+    }
+
+    let tokens = quote! {
+        async fn #slash_cmd(
+            ctx: std::sync::Arc<crate::core::Context>,
+            mut command: crate::util::interaction::InteractionCommand,
+        ) -> eyre::Result<()> {
+            let input = crate::util::ext::InteractionCommandExt::input_data(&mut command);
+
+            let args = match <#name as twilight_interactions::command::CommandModel>::from_interaction(input) {
+                Ok(args) => args,
+                Err(err) => {
+                    return crate::core::commands::slash::option_error(&ctx, &command, #static_name.flags, err)
+                        .await
+                }
+            };
+
+            match args {
+                #(#arms)*
+            }
+        }
+    };
+
+    Ok(tokens)
+}
+
+/// Variants of the subcommand group in `#[subcommands(...)]`
+fn parse_subcommands(attrs: &[Attribute]) -> Result<Option<Vec<Ident>>> {
+    let attr_opt = attrs.iter().find(|attr| match attr.path.get_ident() {
+        Some(ident) => ident == "subcommands",
+        None => false,
+    });
+
+    let Some(attr) = attr_opt else {
+        return Ok(None);
+    };
+
+    let Parenthesised(subcommands) = syn::parse2(attr.tokens.clone())?;
+
+    if subcommands.is_empty() {
+        return Err(Error::new_spanned(attr, "expected at least one subcommand"));
+    }
+
+    Ok(Some(subcommands.into_iter().collect()))
+}
@@ -1,9 +1,11 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use syn::{DeriveInput, Error, Result, Visibility};
+use syn::{Attribute, Data, DeriveInput, Error, Result, Visibility};
 
 use crate::flags::parse_flags;
 
+mod dispatch;
+
 pub fn derive(input: DeriveInput) -> Result<TokenStream> {
     match input.vis {
         Visibility::Public(_) => {}
@@ -15,23 +17,61 @@ pub fn derive(input: DeriveInput) -> Result<TokenStream> {
     let static_name = format_ident!("{}_SLASH", name_str.to_uppercase());
     let slash_cmd = format_ident!("slash_{}", name_str.to_lowercase());
     let exec = format_ident!("{slash_cmd}__");
+    let entry = format_ident!("{static_name}_ENTRY");
     let flags = parse_flags(&input.attrs)?.into_tokens();
     let path = quote!(crate::core::commands::slash::SlashCommand);
 
+    let dispatch = match input.data {
+        Data::Enum(ref data) => dispatch::subcommands(&name, &slash_cmd, &static_name, data)?,
+        _ => TokenStream::new(),
+    };
+
+    let (autocomplete, autocomplete_exec) = if has_autocomplete(&input.attrs) {
+        let autocomplete_cmd = format_ident!("autocomplete_{}", name_str.to_lowercase());
+        let exec = format_ident!("{autocomplete_cmd}__");
+
+        let tokens = quote! {
+            pub fn #exec(
+                ctx: std::sync::Arc<crate::core::Context>,
+                command: crate::util::interaction::InteractionCommand,
+            ) -> crate::core::commands::slash::CommandResult {
+                Box::pin(crate::core::commands::slash::autocomplete(ctx, command, #autocomplete_cmd))
+            }
+        };
+
+        (quote!(Some(#exec)), tokens)
+    } else {
+        (quote!(None), TokenStream::new())
+    };
+
     let tokens = quote! {
         pub static #static_name: #path = #path {
             create: #name::create_command,
+            name: <#name as twilight_interactions::command::CreateCommand>::NAME,
             exec: #exec,
             flags: #flags,
+            autocomplete: #autocomplete,
         };
 
+        #[linkme::distributed_slice(crate::core::commands::slash::COMMAND_LIST)]
+        static #entry: crate::core::commands::slash::InteractionCommand =
+            crate::core::commands::slash::InteractionCommand::Chat(&#static_name);
+
         pub fn #exec(
             ctx: std::sync::Arc<crate::core::Context>,
             command: crate::util::interaction::InteractionCommand,
         ) -> crate::core::commands::slash::CommandResult {
             Box::pin(#slash_cmd(ctx, command))
         }
+
+        #dispatch
+
+        #autocomplete_exec
     };
 
     Ok(tokens)
 }
+
+fn has_autocomplete(attrs: &[Attribute]) -> bool {
+    attrs.iter().any(|attr| attr.path.is_ident("autocomplete"))
+}
@@ -0,0 +1,237 @@
+## Rejected interactions
+
+error-no-authority-message = Du bist nicht berechtigt, mit dieser Nachricht zu interagieren
+error-no-authority-command = Du bist nicht berechtigt, diesen Befehl zu benutzen
+error-only-guilds = Dieser Befehl kann nur auf Servern benutzt werden
+error-only-owner = Dieser Befehl kann nur vom Besitzer des Bots benutzt werden
+error-cooldown = Du machst das zu oft, versuche es <t:{ $timestamp }:R> erneut
+error-invalid-options = Ungültige Optionen: { $error }
+error-incident = Etwas ist schiefgelaufen. Nenne den Vorfall `{ $incident }`, wenn du das meldest
+
+## Github issue builder
+
+create-issue-title = Github-Issue erstellen
+create-issue-message = Nachricht
+create-issue-jump = Anzeigen
+create-issue-author = Autor
+create-issue-issue-title = Titel des Issues
+create-issue-labels = Labels
+create-issue-repository = Repository
+create-issue-created = Issue erstellt
+create-issue-link = Link
+create-issue-create = Erstellen
+create-issue-select-repository = Wähle ein Repository
+create-issue-select-labels = Wähle mindestens ein Label
+create-issue-title-input = Titel
+create-issue-title-modal = Gib einen Titel für das Issue an
+create-issue-author-modal = Gib einen Autor für das Issue an
+thread-opened =
+    Neue Kommentare auf github werden in diesen Thread gespiegelt.
+    Beginne eine Nachricht mit `!gh`, um sie als Kommentar auf github zu posten.
+thread-new-comment = Neuer Kommentar auf github
+
+## Pagination
+
+pagination-footer = Seite { $page }/{ $last }
+pagination-modal = Zu einer Seite springen
+pagination-input = Seitenzahl
+pagination-placeholder = Zahl zwischen 1 und { $last }
+
+## Github issue actions
+
+issue-unknown-repository = Unbekanntes Repository `{ $repo }`
+issue-update-failed = Das Issue { $issue } konnte nicht aktualisiert werden
+issue-confirm-title = Bestätigung erforderlich
+issue-confirm-close-completed = Willst du { $issue } wirklich als erledigt schließen?
+issue-confirm-close-not-planned = Willst du { $issue } wirklich als nicht geplant schließen?
+issue-confirm-reopen = Willst du { $issue } wirklich erneut öffnen?
+issue-confirm-add-labels = Willst du { $issue } wirklich die Labels `{ $labels }` hinzufügen?
+issue-confirm-remove-label = Willst du das Label `{ $label }` wirklich von { $issue } entfernen?
+issue-confirm-assign = Willst du { $issue } wirklich `{ $users }` zuweisen?
+issue-confirm-unassign = Willst du `{ $users }` wirklich von { $issue } entfernen?
+issue-confirm-lock = Willst du { $issue } wirklich sperren?
+issue-confirm = Bestätigen
+issue-cancel = Abbrechen
+issue-cancelled = Abgebrochen
+issue-closed-completed = Als erledigt geschlossen
+issue-closed-not-planned = Als nicht geplant geschlossen
+issue-reopened = Erneut geöffnet
+issue-labels-added = Labels hinzugefügt: { $labels }
+issue-label-removed = Label entfernt: { $label }
+issue-assigned = Zugewiesen: { $users }
+issue-unassigned = Zuweisung entfernt: { $users }
+issue-locked = Unterhaltung gesperrt
+issue-state-open = offen
+issue-state-closed = geschlossen
+issue-state-unknown = unbekannt
+
+## Issue comments
+
+comment-title = Kommentar zu { $issue }
+comment-field = Kommentar
+comment-created = Kommentar erstellt
+comment-link = Link
+comment-write = Kommentar schreiben
+comment-post = Veröffentlichen
+comment-modal = Kommentiere das Issue
+
+## Issue search
+
+search-title = Issue-Suche
+search-author = von `{ $author }`
+search-empty = Keine Issues gefunden
+search-failed = Die Suche nach Issues ist fehlgeschlagen
+search-invalid-value = `{ $value }` darf keine Anführungszeichen enthalten
+
+## Reports of a user
+
+user-reports-title = Von { $name } gemeldete Issues
+user-reports-title-id = Vom Nutzer { $user } gemeldete Issues
+user-reports-empty = Aus Nachrichten von <@{ $user }> wurden keine Issues erstellt
+user-reports-failed = Die Meldungen konnten nicht gesammelt werden
+
+## Reports
+
+reports-disabled = Meldungen sind nicht aktiviert
+reports-submit-modal = Reiche eine Meldung ein
+reports-sent = Danke, deine Meldung wurde an die Mitarbeiter gesendet
+reports-title = Titel
+reports-description = Beschreibung
+reports-description-placeholder = Was ist passiert und was hast du erwartet?
+reports-steps = Schritte zum Reproduzieren
+reports-version = Version
+reports-title-prefix = Meldung: { $title }
+reports-reporter = Melder
+reports-outcome = Ergebnis
+reports-outcome-rejected = Abgelehnt von `@{ $reviewer }`: { $reason }
+reports-outcome-duplicate = Von `@{ $reviewer }` als Duplikat von { $issue } markiert
+reports-notice = Hinweis
+reports-accept = Annehmen
+reports-edit = Bearbeiten
+reports-reject = Ablehnen
+reports-duplicate = Als Duplikat markieren
+reports-edit-modal = Bearbeite die Meldung
+reports-reason = Grund
+reports-reject-modal = Lehne die Meldung ab
+reports-issue = Issue
+reports-issue-placeholder = repository#nummer
+reports-duplicate-modal = Markiere die Meldung als Duplikat
+reports-invalid-issue = `{ $value }` hat nicht die Form `repository#nummer`
+reports-rejected-title = Deine Meldung "{ $title }" wurde abgelehnt
+reports-rejected-reason = **Grund:** { $reason }
+reports-duplicate-title = Deine Meldung "{ $title }" ist ein Duplikat
+reports-duplicate-tracked = Sie wird bereits in { $issue } verfolgt
+
+## Forum posts
+
+forum-not-configured = Es ist kein Forumskanal konfiguriert
+forum-not-a-post = Dieser Befehl kann nur in einem Beitrag von <#{ $channel }> benutzt werden
+forum-post-has-issue = Dieser Beitrag hat bereits ein [Issue]({ $url })
+forum-post-in-progress = Für diesen Beitrag wird bereits ein Issue erstellt
+forum-issue-failed = Das Issue konnte nicht erstellt werden
+forum-issue-created = Issue erstellt
+forum-post-linked =
+    Für diesen Beitrag wurde ein github-Issue erstellt.
+    Neue Kommentare werden hier gespiegelt; beginne eine Nachricht mit `!gh`, um auf github zu antworten.
+forum-post-resolved = Das github-Issue wurde geschlossen; dieser Beitrag ist nun gelöst.
+
+## Configuration
+
+config-reloaded = Konfiguration neu geladen
+config-reload-failed = Die Konfiguration konnte nicht neu geladen werden: { $error }
+
+## Commands
+
+ping-desc = Prüfe, ob der Bot online ist
+
+report-name = melden
+report-desc = Melde einen Fehler oder schlage den Mitarbeitern eine Funktion vor
+
+accept-post-name = beitrag-annehmen
+accept-post-desc = Erstelle ein Github-Issue aus diesem Forumsbeitrag
+
+admin-desc = Verwalte den Bot
+admin-reload-name = neu-laden
+admin-reload-desc = Lade die Konfigurationsdatei neu
+
+create-github-issue-name = Github-Issue erstellen
+show-this-user-s-reports-name = Meldungen dieses Nutzers anzeigen
+
+issue-desc = Verwalte Github-Issues
+
+issue-close-name = schließen
+issue-close-desc = Schließe ein Issue
+issue-close-repo-desc = Repository des Issues
+issue-close-number-name = nummer
+issue-close-number-desc = Nummer des Issues
+issue-close-reason-name = grund
+issue-close-reason-desc = Grund für das Schließen des Issues
+issue-close-reason-completed = Erledigt
+issue-close-reason-not_planned = Nicht geplant
+
+issue-reopen-name = wiedereröffnen
+issue-reopen-desc = Öffne ein geschlossenes Issue erneut
+issue-reopen-repo-desc = Repository des Issues
+issue-reopen-number-name = nummer
+issue-reopen-number-desc = Nummer des Issues
+
+issue-label-desc = Füge Labels eines Issues hinzu oder entferne sie
+issue-label-add-name = hinzufügen
+issue-label-add-desc = Füge einem Issue Labels hinzu
+issue-label-add-repo-desc = Repository des Issues
+issue-label-add-number-name = nummer
+issue-label-add-number-desc = Nummer des Issues
+issue-label-add-labels-desc = Hinzuzufügende Labels, durch Kommas getrennt
+issue-label-remove-name = entfernen
+issue-label-remove-desc = Entferne ein Label von einem Issue
+issue-label-remove-repo-desc = Repository des Issues
+issue-label-remove-number-name = nummer
+issue-label-remove-number-desc = Nummer des Issues
+issue-label-remove-label-desc = Zu entfernendes Label
+
+issue-assign-name = zuweisen
+issue-assign-desc = Weise einem Issue Github-Nutzer zu
+issue-assign-repo-desc = Repository des Issues
+issue-assign-number-name = nummer
+issue-assign-number-desc = Nummer des Issues
+issue-assign-users-name = nutzer
+issue-assign-users-desc = Github-Nutzernamen, durch Kommas getrennt
+
+issue-unassign-name = zuweisung-entfernen
+issue-unassign-desc = Entferne Github-Nutzer von einem Issue
+issue-unassign-repo-desc = Repository des Issues
+issue-unassign-number-name = nummer
+issue-unassign-number-desc = Nummer des Issues
+issue-unassign-users-name = nutzer
+issue-unassign-users-desc = Github-Nutzernamen, durch Kommas getrennt
+
+issue-comment-name = kommentieren
+issue-comment-desc = Kommentiere ein Issue
+issue-comment-repo-desc = Repository des Issues
+issue-comment-number-name = nummer
+issue-comment-number-desc = Nummer des Issues
+
+issue-lock-name = sperren
+issue-lock-desc = Sperre die Unterhaltung eines Issues
+issue-lock-repo-desc = Repository des Issues
+issue-lock-number-name = nummer
+issue-lock-number-desc = Nummer des Issues
+issue-lock-reason-name = grund
+issue-lock-reason-desc = Grund für das Sperren des Issues
+issue-lock-reason-off_topic = Themenfremd
+issue-lock-reason-too_heated = Zu hitzig
+issue-lock-reason-resolved = Gelöst
+issue-lock-reason-spam = Spam
+
+issue-search-name = suchen
+issue-search-desc = Suche nach Github-Issues
+issue-search-query-name = suchbegriff
+issue-search-query-desc = Text, nach dem gesucht wird
+issue-search-repo-desc = Nur in diesem Repository suchen
+issue-search-state-name = status
+issue-search-state-desc = Nur nach Issues mit diesem Status suchen
+issue-search-state-open = Offen
+issue-search-state-closed = Geschlossen
+issue-search-label-desc = Nur nach Issues mit diesem Label suchen
+issue-search-author-name = autor
+issue-search-author-desc = Nur nach Issues dieses Github-Nutzers suchen
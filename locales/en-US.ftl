@@ -0,0 +1,148 @@
+# Messages of the bot in english; other locales fall back to these.
+#
+# Command names and descriptions are defined in the code so this file doesn't
+# list them. Other locales translate them with keys like `issue-close-repo-desc`:
+# the command's name followed by its subcommands and option, and `-name` or `-desc`.
+# Choices of an option are keyed by their value, e.g. `issue-close-reason-completed`.
+
+## Rejected interactions
+
+error-no-authority-message = You lack authority to interact with this message
+error-no-authority-command = You lack authority to use this command
+error-only-guilds = This command can only be used in servers
+error-only-owner = This command can only be used by the bot owner
+error-cooldown = You're doing this too often, try again <t:{ $timestamp }:R>
+error-invalid-options = Invalid options: { $error }
+error-incident = Something went wrong. Mention the incident `{ $incident }` if you report this
+
+## Github issue builder
+
+create-issue-title = Github issue builder
+create-issue-message = Message
+create-issue-jump = Jump
+create-issue-author = Author
+create-issue-issue-title = Issue title
+create-issue-labels = Labels
+create-issue-repository = Repository
+create-issue-created = Issue created
+create-issue-link = Link
+create-issue-create = Create
+create-issue-select-repository = Select one repository
+create-issue-select-labels = Select at least one label
+create-issue-title-input = Title
+create-issue-title-modal = Specify a title for the issue
+create-issue-author-modal = Specify an author for the issue
+thread-opened =
+    New github comments on this issue will be mirrored into this thread.
+    Prefix a message with `!gh` to post it as comment on github.
+thread-new-comment = New comment on github
+
+## Pagination
+
+pagination-footer = Page { $page }/{ $last }
+pagination-modal = Jump to a page
+pagination-input = Page number
+pagination-placeholder = Number between 1 and { $last }
+
+## Github issue actions
+
+issue-unknown-repository = Unknown repository `{ $repo }`
+issue-update-failed = Failed to update issue { $issue }
+issue-confirm-title = Confirmation required
+issue-confirm-close-completed = Do you really want to close { $issue } as completed?
+issue-confirm-close-not-planned = Do you really want to close { $issue } as not planned?
+issue-confirm-reopen = Do you really want to reopen { $issue }?
+issue-confirm-add-labels = Do you really want to add the labels `{ $labels }` to { $issue }?
+issue-confirm-remove-label = Do you really want to remove the label `{ $label }` from { $issue }?
+issue-confirm-assign = Do you really want to assign `{ $users }` to { $issue }?
+issue-confirm-unassign = Do you really want to unassign `{ $users }` from { $issue }?
+issue-confirm-lock = Do you really want to lock { $issue }?
+issue-confirm = Confirm
+issue-cancel = Cancel
+issue-cancelled = Cancelled
+issue-closed-completed = Closed as completed
+issue-closed-not-planned = Closed as not planned
+issue-reopened = Reopened
+issue-labels-added = Added labels: { $labels }
+issue-label-removed = Removed label: { $label }
+issue-assigned = Assigned: { $users }
+issue-unassigned = Unassigned: { $users }
+issue-locked = Locked conversation
+issue-state-open = open
+issue-state-closed = closed
+issue-state-unknown = unknown
+
+## Issue comments
+
+comment-title = Comment on { $issue }
+comment-field = Comment
+comment-created = Comment created
+comment-link = Link
+comment-write = Write comment
+comment-post = Post
+comment-modal = Comment on the issue
+
+## Issue search
+
+search-title = Issue search
+search-author = by `{ $author }`
+search-empty = No issues found
+search-failed = Failed to search issues
+search-invalid-value = `{ $value }` must not contain quotes
+
+## Reports of a user
+
+user-reports-title = Issues reported by { $name }
+user-reports-title-id = Issues reported by user { $user }
+user-reports-empty = No issues were created from messages of <@{ $user }>
+user-reports-failed = Failed to gather reports
+
+## Reports
+
+reports-disabled = Reports are not enabled
+reports-submit-modal = Submit a report
+reports-sent = Thanks, your report was sent to the staff
+reports-title = Title
+reports-description = Description
+reports-description-placeholder = What happened and what did you expect to happen?
+reports-steps = Steps to reproduce
+reports-version = Version
+reports-title-prefix = Report: { $title }
+reports-reporter = Reporter
+reports-outcome = Outcome
+reports-outcome-rejected = Rejected by `@{ $reviewer }`: { $reason }
+reports-outcome-duplicate = Marked as duplicate of { $issue } by `@{ $reviewer }`
+reports-notice = Notice
+reports-accept = Accept
+reports-edit = Edit
+reports-reject = Reject
+reports-duplicate = Mark duplicate
+reports-edit-modal = Edit the report
+reports-reason = Reason
+reports-reject-modal = Reject the report
+reports-issue = Issue
+reports-issue-placeholder = repository#number
+reports-duplicate-modal = Mark the report as duplicate
+reports-invalid-issue = `{ $value }` is not of the form `repository#number`
+reports-rejected-title = Your report "{ $title }" was rejected
+reports-rejected-reason = **Reason:** { $reason }
+reports-duplicate-title = Your report "{ $title }" is a duplicate
+reports-duplicate-tracked = It is already being tracked in { $issue }
+
+## Forum posts
+
+forum-not-configured = No forum channel is configured
+forum-not-a-post = This command can only be used inside a post of <#{ $channel }>
+forum-post-has-issue = This post already has an [issue]({ $url })
+forum-post-in-progress = An issue is being created for this post already
+forum-issue-failed = Failed to create issue
+forum-issue-created = Issue created
+forum-post-linked =
+    A github issue was created for this post.
+    New comments will be mirrored here; prefix a message with `!gh` to reply on github.
+forum-post-resolved = The github issue was closed; this post is now resolved.
+
+## Configuration
+
+config-reloaded = Configuration reloaded
+config-reload-failed = Failed to reload the configuration: { $error }
@@ -0,0 +1,172 @@
+use eyre::Result;
+use futures::future::{self, BoxFuture};
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle},
+        Component,
+    },
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::{
+    core::{
+        audit::{self, AuditAction, AuditEntry, Operator},
+        Context,
+    },
+    util::{
+        builder::EmbedBuilder,
+        ext::Authored,
+        github::{IssueAction, IssueRef},
+        interaction::InteractionComponent,
+        locale::t,
+    },
+};
+
+use super::{ComponentResult, IActiveMessage};
+
+pub struct ConfirmAction {
+    issue: IssueRef,
+    action: IssueAction,
+    status: ConfirmStatus,
+    guild: Option<Id<GuildMarker>>,
+    /// User that confirmed or cancelled the action
+    operator: Option<Operator>,
+    locale: String,
+}
+
+enum ConfirmStatus {
+    Pending,
+    Confirmed,
+    Cancelled,
+    Done(EmbedBuilder),
+}
+
+impl ConfirmAction {
+    pub fn new(issue: IssueRef, action: IssueAction, locale: &str) -> Self {
+        Self {
+            issue,
+            action,
+            status: ConfirmStatus::Pending,
+            guild: None,
+            operator: None,
+            locale: locale.to_owned(),
+        }
+    }
+
+    /// The unconfirmed action, e.g. `close as completed owner/repo#1`
+    fn describe(&self) -> String {
+        format!(
+            "{} [{}]({})",
+            self.action.phrase(),
+            self.issue,
+            self.issue.url()
+        )
+    }
+
+    fn audit(&self, action: AuditAction) -> AuditEntry {
+        AuditEntry::new(self.guild, self.operator.clone(), action)
+    }
+}
+
+impl IActiveMessage for ConfirmAction {
+    fn build_page<'a>(&'a mut self, ctx: &'a Context) -> BoxFuture<'a, Result<EmbedBuilder>> {
+        let embed = match self.status {
+            ConfirmStatus::Pending => self.action.confirmation(&self.issue, &self.locale),
+            ConfirmStatus::Done(ref embed) => embed.clone(),
+            ConfirmStatus::Cancelled => {
+                let fut = async move {
+                    let draft = self.describe();
+                    let entry = self.audit(AuditAction::DraftAbandoned { draft });
+                    audit::record(ctx, entry).await;
+
+                    let embed = EmbedBuilder::new()
+                        .title(self.issue.to_string())
+                        .url(self.issue.url())
+                        .description(t!(&self.locale, "issue-cancelled"));
+
+                    self.status = ConfirmStatus::Done(embed.clone());
+
+                    Ok(embed)
+                };
+
+                return Box::pin(fut);
+            }
+            ConfirmStatus::Confirmed => {
+                let fut = async move {
+                    let embed = self.issue.apply(ctx, &self.action, &self.locale).await?;
+                    self.status = ConfirmStatus::Done(embed.clone());
+
+                    let action = self.action.audit(&self.issue);
+                    audit::record(ctx, self.audit(action)).await;
+
+                    Ok(embed)
+                };
+
+                return Box::pin(fut);
+            }
+        };
+
+        Box::pin(future::ready(Ok(embed)))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if !matches!(self.status, ConfirmStatus::Pending) {
+            return Vec::new();
+        }
+
+        let confirm = Button {
+            custom_id: Some("action_confirm".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some(t!(&self.locale, "issue-confirm")),
+            style: ButtonStyle::Danger,
+            url: None,
+            sku_id: None,
+        };
+
+        let cancel = Button {
+            custom_id: Some("action_cancel".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some(t!(&self.locale, "issue-cancel")),
+            style: ButtonStyle::Secondary,
+            url: None,
+            sku_id: None,
+        };
+
+        let button_row = ActionRow {
+            components: vec![Component::Button(confirm), Component::Button(cancel)],
+        };
+
+        vec![Component::ActionRow(button_row)]
+    }
+
+    fn handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> BoxFuture<'static, ComponentResult> {
+        self.guild = component.guild_id;
+        self.operator = component.user().ok().map(Operator::from);
+        self.locale.clone_from(&component.locale);
+
+        let res = match component.data.custom_id.as_str() {
+            "action_confirm" => {
+                self.status = ConfirmStatus::Confirmed;
+
+                ComponentResult::BuildPage
+            }
+            "action_cancel" => {
+                self.status = ConfirmStatus::Cancelled;
+
+                ComponentResult::BuildPage
+            }
+            other => ComponentResult::Err(eyre!("Unknown component `{other}`")),
+        };
+
+        Box::pin(future::ready(res))
+    }
+
+    fn draft(&self) -> Option<String> {
+        matches!(self.status, ConfirmStatus::Pending).then(|| self.describe())
+    }
+}
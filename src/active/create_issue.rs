@@ -1,6 +1,8 @@
 use std::{
     fmt::{Display, Formatter, Result as FmtResult, Write},
     mem,
+    sync::Arc,
+    time::Duration,
 };
 
 use eyre::{ContextCompat, Result, WrapErr};
@@ -15,27 +17,37 @@ use twilight_model::{
             embed::EmbedField,
             Component,
         },
-        Message,
+        ChannelType, Message,
     },
     id::{
-        marker::{ChannelMarker, GuildMarker, MessageMarker},
+        marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
         Id,
     },
 };
 
 use crate::{
-    core::Context,
+    core::{
+        audit::{self, AuditAction, AuditEntry, Operator},
+        metrics::{Api, ObserveExt, METRICS},
+        records::{IssueRecord, SourceRecord},
+        Context,
+    },
     util::{
-        builder::{EmbedBuilder, ModalBuilder, TextInputBuilder},
+        builder::{EmbedBuilder, MessageBuilder, ModalBuilder, TextInputBuilder},
+        config::Project,
+        ext::{Authored, ChannelExt},
+        github::{github_time, issue_body},
         interaction::{InteractionComponent, InteractionModal},
+        locale::t,
     },
-    CONFIG,
 };
 
-use super::{ComponentResult, IActiveMessage};
+use super::{ComponentResult, IActiveMessage, ReportSubmission};
 
 pub struct CreateIssue {
+    project: Arc<Project>,
     author: Option<String>,
+    author_id: Id<UserMarker>,
     origin_content: String,
     source: SourceMessageUrl,
     attachments: Vec<String>,
@@ -44,6 +56,11 @@ pub struct CreateIssue {
     active_repository: String,
     labels: Vec<Label>,
     status: CreateIssueStatus,
+    /// Locale of the user that interacted last
+    locale: String,
+    /// User that pressed the create button
+    operator: Option<Operator>,
+    timeout: Option<Duration>,
 }
 
 enum CreateIssueStatus {
@@ -53,9 +70,11 @@ enum CreateIssueStatus {
 }
 
 impl CreateIssue {
-    pub fn new(msg: Message, guild: Id<GuildMarker>) -> Self {
+    pub fn new(msg: Message, guild: Id<GuildMarker>, project: Arc<Project>, locale: &str) -> Self {
         Self {
+            project,
             author: Some(msg.author.name),
+            author_id: msg.author.id,
             origin_content: msg.content,
             source: SourceMessageUrl {
                 guild,
@@ -72,6 +91,40 @@ impl CreateIssue {
             active_repository: String::new(),
             labels: Vec::new(),
             status: CreateIssueStatus::Creating,
+            locale: locale.to_owned(),
+            operator: None,
+            timeout: Some(Duration::from_secs(60)),
+        }
+    }
+
+    /// Prefill the builder with an accepted report of the given queue message
+    pub fn from_report(
+        report: &ReportSubmission,
+        msg: &Message,
+        guild: Id<GuildMarker>,
+        project: Arc<Project>,
+        locale: &str,
+    ) -> Self {
+        Self {
+            project,
+            author: Some(report.reporter_name.clone()),
+            author_id: report.reporter_id,
+            origin_content: report.content(),
+            source: SourceMessageUrl {
+                guild,
+                channel: msg.channel_id,
+                msg: msg.id,
+            },
+            attachments: Vec::new(),
+            title: Some(report.title.clone()),
+            repositories: Vec::new(),
+            active_repository: String::new(),
+            labels: Vec::new(),
+            status: CreateIssueStatus::Creating,
+            locale: locale.to_owned(),
+            operator: None,
+            // The report stays in the queue until its issue is created
+            timeout: None,
         }
     }
 
@@ -82,41 +135,125 @@ impl CreateIssue {
 
         let labels: Vec<_> = self.labels.iter().map(Label::to_string).collect();
         let builder = ctx.github.issues(
-            ctx.config.github_config.owner.clone(),
+            self.project.github_config.owner.clone(),
             self.active_repository.clone(),
         );
-        let mut body = String::with_capacity(self.origin_content.len() + 32);
+        let body = issue_body(
+            &self.origin_content,
+            &self.attachments,
+            self.author.as_deref(),
+            &self.source.to_string(),
+        );
 
-        if !self.origin_content.is_empty() {
-            for line in self.origin_content.lines() {
-                let _ = writeln!(body, "> {line}");
+        builder
+            .create(title.to_owned())
+            .body(body)
+            .labels(labels)
+            .send()
+            .observe(Api::Github, "issues.create")
+            .await
+            .wrap_err("Failed to create issue")
+    }
+
+    /// Remember the created issue, open a thread to mirror its comments, and audit it
+    async fn track_issue(&self, ctx: &Context, issue: &Issue) {
+        let thread = match self.open_thread(ctx, issue).await {
+            Ok(thread) => thread,
+            Err(err) => {
+                warn!(?err, "Failed to open thread for issue");
+
+                None
             }
+        };
 
-            body.push('\n');
-        }
+        let source = SourceRecord {
+            guild: self.source.guild,
+            channel: self.source.channel,
+            message: self.source.msg,
+            author_id: Some(self.author_id),
+            author_name: self.author.clone(),
+        };
 
-        if !self.attachments.is_empty() {
-            body.push_str("Attachments:\n");
+        let record = IssueRecord {
+            owner: self.project.github_config.owner.clone(),
+            repo: self.active_repository.clone(),
+            number: issue.number,
+            url: issue.html_url.to_string(),
+            created_at: github_time(issue.created_at.timestamp()),
+            source: Some(source),
+            thread,
+            last_comment: None,
+            synced_at: None,
+            own_comments: Vec::new(),
+            forum_post: false,
+            resolved: false,
+            locale: self.locale.clone(),
+        };
 
-            for url in self.attachments.iter() {
-                let _ = writeln!(body, "![attachment]({url})");
-            }
+        if let Err(err) = ctx.records.insert(record).await {
+            warn!(?err, "Failed to store issue record");
         }
 
-        let _ = match self.author {
-            Some(ref author) => {
-                write!(body, "[Original message by @{author}]({})", self.source)
+        // An accepted report leaves the queue once its issue exists
+        if let Err(err) = ctx.reports.remove(self.source.msg).await {
+            warn!(?err, "Failed to remove pending report");
+        }
+
+        let action = AuditAction::IssueCreated {
+            repo: self.active_repository.clone(),
+            issue: issue.html_url.to_string(),
+            title: issue.title.clone(),
+            labels: self.labels.iter().map(Label::to_string).collect(),
+            source: Some(self.source.to_string()),
+        };
+
+        let entry = AuditEntry::new(Some(self.source.guild), self.operator.clone(), action);
+        audit::record(ctx, entry).await;
+    }
+
+    async fn open_thread(&self, ctx: &Context, issue: &Issue) -> Result<Option<Id<ChannelMarker>>> {
+        let Some(ref threads) = self.project.threads else {
+            return Ok(None);
+        };
+
+        let name: String = format!("#{} {}", issue.number, issue.title)
+            .chars()
+            .take(100)
+            .collect();
+
+        let response = match threads.channel_id_as_marker() {
+            Some(channel) => {
+                ctx.http
+                    .create_thread(channel, &name, ChannelType::PublicThread)
+                    .observe(Api::Discord, "create_thread")
+                    .await
+            }
+            None => {
+                ctx.http
+                    .create_thread_from_message(self.source.channel, self.source.msg, &name)
+                    .observe(Api::Discord, "create_thread_from_message")
+                    .await
             }
-            None => write!(body, "[Original message]({})", self.source),
         };
 
-        builder
-            .create(title.to_owned())
-            .body(body)
-            .labels(labels)
-            .send()
+        let thread = response
+            .wrap_err("Failed to create thread")?
+            .model()
             .await
-            .wrap_err("Failed to create issue")
+            .wrap_err("Failed to deserialize thread")?;
+
+        let embed = EmbedBuilder::new()
+            .title(name)
+            .url(issue.html_url.to_string())
+            .description(t!(&self.locale, "thread-opened"));
+
+        thread
+            .id
+            .create_message(ctx, &MessageBuilder::new().embed(embed), None)
+            .await
+            .wrap_err("Failed to create thread message")?;
+
+        Ok(Some(thread.id))
     }
 }
 
@@ -128,6 +265,8 @@ impl IActiveMessage for CreateIssue {
             CreateIssueStatus::Ready => {
                 let fut = async move {
                     let issue = self.create_issue(ctx).await?;
+                    METRICS.issue_created(&self.active_repository);
+                    self.track_issue(ctx, &issue).await;
 
                     self.status = CreateIssueStatus::Done {
                         url: issue.html_url.to_string(),
@@ -142,19 +281,23 @@ impl IActiveMessage for CreateIssue {
 
         let msg = EmbedField {
             inline: true,
-            name: "Message".to_owned(),
-            value: format!("[Jump]({})", self.source),
+            name: t!(&self.locale, "create-issue-message"),
+            value: format!(
+                "[{}]({})",
+                t!(&self.locale, "create-issue-jump"),
+                self.source
+            ),
         };
 
         let author = self.author.as_ref().map(|author| EmbedField {
             inline: true,
-            name: "Author".to_owned(),
+            name: t!(&self.locale, "create-issue-author"),
             value: format!("`@{author}`"),
         });
 
         let title = EmbedField {
             inline: false,
-            name: "Issue title".to_owned(),
+            name: t!(&self.locale, "create-issue-issue-title"),
             value: match self.title.as_ref() {
                 Some(title) => title.to_owned(),
                 None => "-".to_owned(),
@@ -163,7 +306,7 @@ impl IActiveMessage for CreateIssue {
 
         let labels = EmbedField {
             inline: false,
-            name: "Labels".to_owned(),
+            name: t!(&self.locale, "create-issue-labels"),
             value: match self.labels.as_slice() {
                 [] => "-".to_owned(),
                 labels => Label::list_to_str(labels),
@@ -172,7 +315,7 @@ impl IActiveMessage for CreateIssue {
 
         let repository = EmbedField {
             inline: false,
-            name: "Repository".to_owned(),
+            name: t!(&self.locale, "create-issue-repository"),
             value: self.active_repository.clone(),
         };
 
@@ -184,15 +327,15 @@ impl IActiveMessage for CreateIssue {
         if let Some(url) = issue_url {
             let issue = EmbedField {
                 inline: false,
-                name: "Issue created".to_owned(),
-                value: format!("[**Link**]({url})"),
+                name: t!(&self.locale, "create-issue-created"),
+                value: format!("[**{}**]({url})", t!(&self.locale, "create-issue-link")),
             };
 
             fields.push(issue);
         }
 
         let embed = EmbedBuilder::new()
-            .title("Github issue builder")
+            .title(t!(&self.locale, "create-issue-title"))
             .fields(fields);
 
         Box::pin(future::ready(Ok(embed)))
@@ -210,7 +353,7 @@ impl IActiveMessage for CreateIssue {
             custom_id: Some("issue_title".to_owned()),
             disabled: false,
             emoji: None,
-            label: Some("Issue title".to_owned()),
+            label: Some(t!(&self.locale, "create-issue-issue-title")),
             style: ButtonStyle::Primary,
             url: None,
             sku_id: None,
@@ -220,7 +363,7 @@ impl IActiveMessage for CreateIssue {
             custom_id: Some("issue_author".to_owned()),
             disabled: false,
             emoji: None,
-            label: Some("Author".to_owned()),
+            label: Some(t!(&self.locale, "create-issue-author")),
             style: ButtonStyle::Secondary,
             url: None,
             sku_id: None,
@@ -230,7 +373,7 @@ impl IActiveMessage for CreateIssue {
             custom_id: Some("issue_create".to_owned()),
             disabled: self.title.is_none() || self.labels.is_empty(),
             emoji: None,
-            label: Some("Create".to_owned()),
+            label: Some(t!(&self.locale, "create-issue-create")),
             style: ButtonStyle::Success,
             url: None,
             sku_id: None,
@@ -244,9 +387,8 @@ impl IActiveMessage for CreateIssue {
             ],
         };
 
-        let repository_options: Vec<_> = CONFIG
-            .get()
-            .unwrap()
+        let repository_options: Vec<_> = self
+            .project
             .github_config
             .repositories
             .clone()
@@ -266,15 +408,14 @@ impl IActiveMessage for CreateIssue {
             max_values: Some(1),
             min_values: Some(1),
             options: Some(repository_options),
-            placeholder: Some("Select one repository".to_owned()),
+            placeholder: Some(t!(&self.locale, "create-issue-select-repository")),
             channel_types: None,
             default_values: None,
             kind: SelectMenuType::Text,
         };
 
-        let label_options: Vec<_> = CONFIG
-            .get()
-            .unwrap()
+        let label_options: Vec<_> = self
+            .project
             .issue_labels
             .issue_types
             .clone()
@@ -294,7 +435,7 @@ impl IActiveMessage for CreateIssue {
             max_values: Some(label_options.len() as u8),
             min_values: Some(1),
             options: Some(label_options),
-            placeholder: Some("Select at least one label".to_owned()),
+            placeholder: Some(t!(&self.locale, "create-issue-select-labels")),
             channel_types: None,
             default_values: None,
             kind: SelectMenuType::Text,
@@ -320,25 +461,29 @@ impl IActiveMessage for CreateIssue {
         component: &mut InteractionComponent,
     ) -> BoxFuture<'static, ComponentResult> {
         fn inner(this: &mut CreateIssue, component: &mut InteractionComponent) -> ComponentResult {
+            // The builder may be shared so it follows whoever uses it
+            this.locale.clone_from(&component.locale);
+
             match component.data.custom_id.as_str() {
                 "issue_title" => {
-                    let input = TextInputBuilder::new("title", "Title")
+                    let label = t!(&this.locale, "create-issue-title-input");
+                    let input = TextInputBuilder::new("title", label)
                         .required(true)
                         .max_len(64);
 
-                    let modal = ModalBuilder::new("issue_title", "Specify a title for the issue")
-                        .input(input);
+                    let title = t!(&this.locale, "create-issue-title-modal");
+                    let modal = ModalBuilder::new("issue_title", title).input(input);
 
                     ComponentResult::CreateModal(modal)
                 }
                 "issue_author" => {
-                    let input = TextInputBuilder::new("author", "Author")
+                    let label = t!(&this.locale, "create-issue-author");
+                    let input = TextInputBuilder::new("author", label)
                         .required(false)
                         .max_len(32);
 
-                    let modal =
-                        ModalBuilder::new("issue_author", "Specify an author for the issue")
-                            .input(input);
+                    let title = t!(&this.locale, "create-issue-author-modal");
+                    let modal = ModalBuilder::new("issue_author", title).input(input);
 
                     ComponentResult::CreateModal(modal)
                 }
@@ -351,6 +496,7 @@ impl IActiveMessage for CreateIssue {
                 }
                 "issue_create" => {
                     this.status = CreateIssueStatus::Ready;
+                    this.operator = component.user().ok().map(Operator::from);
 
                     ComponentResult::BuildPage
                 }
@@ -401,6 +547,23 @@ impl IActiveMessage for CreateIssue {
 
         Box::pin(future::ready(inner(self, modal)))
     }
+
+    fn draft(&self) -> Option<String> {
+        if !matches!(self.status, CreateIssueStatus::Creating) {
+            return None;
+        }
+
+        let title = self.title.as_deref().unwrap_or("untitled");
+
+        Some(format!(
+            "Issue `{title}` from [this message]({})",
+            self.source
+        ))
+    }
+
+    fn until_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
 }
 
 struct SourceMessageUrl {
@@ -0,0 +1,216 @@
+use std::mem;
+
+use eyre::{ContextCompat, Result};
+use futures::future::{self, BoxFuture};
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle, TextInputStyle},
+        embed::EmbedField,
+        Component,
+    },
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::{
+    core::{
+        audit::{self, AuditAction, AuditEntry, Operator},
+        Context,
+    },
+    util::{
+        builder::{EmbedBuilder, ModalBuilder, TextInputBuilder},
+        ext::Authored,
+        github::IssueRef,
+        interaction::{InteractionComponent, InteractionModal},
+        locale::t,
+    },
+};
+
+use super::{ComponentResult, IActiveMessage};
+
+pub struct IssueComment {
+    issue: IssueRef,
+    author: String,
+    content: Option<String>,
+    status: IssueCommentStatus,
+    guild: Option<Id<GuildMarker>>,
+    /// User that pressed the post button
+    operator: Option<Operator>,
+    locale: String,
+}
+
+enum IssueCommentStatus {
+    Writing,
+    Ready,
+    Done { url: String },
+}
+
+impl IssueComment {
+    pub fn new(issue: IssueRef, author: String, locale: &str) -> Self {
+        Self {
+            issue,
+            author,
+            content: None,
+            status: IssueCommentStatus::Writing,
+            guild: None,
+            operator: None,
+            locale: locale.to_owned(),
+        }
+    }
+
+    async fn create_comment(&self, ctx: &Context) -> Result<String> {
+        let content = self
+            .content
+            .as_deref()
+            .wrap_err("Missing comment content")?;
+        let comment = self.issue.comment(ctx, content, &self.author).await?;
+        let url = comment.html_url.to_string();
+
+        let action = AuditAction::CommentAdded {
+            issue: self.issue.url(),
+            comment: url.clone(),
+        };
+
+        let entry = AuditEntry::new(self.guild, self.operator.clone(), action);
+        audit::record(ctx, entry).await;
+
+        Ok(url)
+    }
+}
+
+impl IActiveMessage for IssueComment {
+    fn build_page<'a>(&'a mut self, ctx: &'a Context) -> BoxFuture<'a, Result<EmbedBuilder>> {
+        let comment_url = match self.status {
+            IssueCommentStatus::Writing => None,
+            IssueCommentStatus::Done { ref mut url } => Some(mem::take(url)),
+            IssueCommentStatus::Ready => {
+                let fut = async move {
+                    let url = self.create_comment(ctx).await?;
+                    self.status = IssueCommentStatus::Done { url };
+
+                    self.build_page(ctx).await
+                };
+
+                return Box::pin(fut);
+            }
+        };
+
+        let mut fields = vec![EmbedField {
+            inline: false,
+            name: t!(&self.locale, "comment-field"),
+            value: match self.content.as_ref() {
+                Some(content) => content.to_owned(),
+                None => "-".to_owned(),
+            },
+        }];
+
+        if let Some(url) = comment_url {
+            let comment = EmbedField {
+                inline: false,
+                name: t!(&self.locale, "comment-created"),
+                value: format!("[**{}**]({url})", t!(&self.locale, "comment-link")),
+            };
+
+            fields.push(comment);
+        }
+
+        let embed = EmbedBuilder::new()
+            .title(t!(
+                &self.locale,
+                "comment-title",
+                issue = self.issue.to_string()
+            ))
+            .url(self.issue.url())
+            .fields(fields);
+
+        Box::pin(future::ready(Ok(embed)))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if !matches!(self.status, IssueCommentStatus::Writing) {
+            return Vec::new();
+        }
+
+        let write = Button {
+            custom_id: Some("comment_write".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some(t!(&self.locale, "comment-write")),
+            style: ButtonStyle::Primary,
+            url: None,
+            sku_id: None,
+        };
+
+        let post = Button {
+            custom_id: Some("comment_post".to_owned()),
+            disabled: self.content.is_none(),
+            emoji: None,
+            label: Some(t!(&self.locale, "comment-post")),
+            style: ButtonStyle::Success,
+            url: None,
+            sku_id: None,
+        };
+
+        let button_row = ActionRow {
+            components: vec![Component::Button(write), Component::Button(post)],
+        };
+
+        vec![Component::ActionRow(button_row)]
+    }
+
+    fn handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> BoxFuture<'static, ComponentResult> {
+        self.locale.clone_from(&component.locale);
+
+        let res = match component.data.custom_id.as_str() {
+            "comment_write" => {
+                let mut input = TextInputBuilder::new("comment", t!(&self.locale, "comment-field"))
+                    .required(true)
+                    .style(TextInputStyle::Paragraph)
+                    .max_len(4000);
+
+                if let Some(ref content) = self.content {
+                    input = input.value(content);
+                }
+
+                let title = t!(&self.locale, "comment-modal");
+                let modal = ModalBuilder::new("comment_write", title).input(input);
+
+                ComponentResult::CreateModal(modal)
+            }
+            "comment_post" => {
+                self.status = IssueCommentStatus::Ready;
+                self.guild = component.guild_id;
+                self.operator = component.user().ok().map(Operator::from);
+
+                ComponentResult::BuildPage
+            }
+            other => ComponentResult::Err(eyre!("Unknown component `{other}`")),
+        };
+
+        Box::pin(future::ready(res))
+    }
+
+    fn handle_modal(&mut self, modal: &mut InteractionModal) -> BoxFuture<'static, Result<()>> {
+        let res = match modal.data.custom_id.as_str() {
+            "comment_write" => modal
+                .data
+                .components
+                .first_mut()
+                .and_then(|row| row.components.first_mut())
+                .wrap_err("Missing modal input")
+                .map(|component| {
+                    self.content = component.value.take().filter(|value| !value.is_empty());
+                }),
+            other => Err(eyre!("Unknown modal `{other}`")),
+        };
+
+        Box::pin(future::ready(res))
+    }
+
+    fn draft(&self) -> Option<String> {
+        matches!(self.status, IssueCommentStatus::Writing)
+            .then(|| format!("Comment on [{}]({})", self.issue, self.issue.url()))
+    }
+}
@@ -1,9 +1,16 @@
-use std::{sync::Arc, time::Duration};
+use std::{
+    panic::AssertUnwindSafe,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 use enum_dispatch::enum_dispatch;
 use eyre::{Report, Result, WrapErr};
 use flexmap::tokio::TokioMutexMap;
-use futures::future::{self, BoxFuture};
+use futures::{
+    future::{self, BoxFuture},
+    FutureExt,
+};
 use tokio::{
     sync::watch::{self, Receiver, Sender},
     time::sleep,
@@ -11,23 +18,34 @@ use tokio::{
 use twilight_model::{
     channel::message::Component,
     id::{
-        marker::{ChannelMarker, MessageMarker},
+        marker::{ChannelMarker, GuildMarker, MessageMarker},
         Id,
     },
 };
 
+mod confirm_action;
 mod create_issue;
+mod issue_comment;
+mod pagination;
+mod report_review;
 
 use crate::{
-    core::Context,
+    core::{
+        audit::{self, AuditAction, AuditEntry},
+        incident::{panic_report, Incident},
+        metrics::{Api, ObserveExt, METRICS},
+        Context,
+    },
     util::{
         builder::{EmbedBuilder, MessageBuilder, ModalBuilder},
-        ext::{ComponentExt, InteractionCommandExt, MessageExt, ModalExt},
+        ext::{Authored, ChannelExt, ComponentExt, InteractionCommandExt, MessageExt, ModalExt},
         interaction::{InteractionCommand, InteractionComponent, InteractionModal},
     },
 };
 
-pub use self::create_issue::*;
+pub use self::{
+    confirm_action::*, create_issue::*, issue_comment::*, pagination::*, report_review::*,
+};
 
 pub struct ActiveMessagesBuilder {
     inner: ActiveMessage,
@@ -67,6 +85,7 @@ impl ActiveMessagesBuilder {
 
             ctx.interaction()
                 .response(&orig.token)
+                .observe(Api::Discord, "response")
                 .await
                 .wrap_err("Failed to get response message")?
         };
@@ -76,19 +95,99 @@ impl ActiveMessagesBuilder {
             .await
             .wrap_err("Failed to deserialize response")?;
 
-        let channel = response.channel_id;
-        let msg = response.id;
-        let (tx, rx) = watch::channel(());
+        let guild = orig.guild_id;
+        Self::track(ctx, active_msg, response.id, response.channel_id, guild).await;
 
-        Self::spawn_timeout(Arc::clone(&ctx), rx, msg, channel);
+        Ok(())
+    }
 
-        let full = FullActiveMessage { active_msg, tx };
-        ctx.active_msgs.insert(msg, full).await;
+    /// Post the active message into a channel instead of responding to an interaction.
+    ///
+    /// Returns the id of the posted message.
+    pub async fn begin_in_channel(
+        self,
+        ctx: Arc<Context>,
+        channel: Id<ChannelMarker>,
+        guild: Id<GuildMarker>,
+    ) -> Result<Id<MessageMarker>> {
+        let Self {
+            inner: mut active_msg,
+            ..
+        } = self;
+
+        let embed = active_msg
+            .build_page(&ctx)
+            .await
+            .wrap_err("Failed to build page")?;
+
+        let components = active_msg.build_components();
+        let builder = MessageBuilder::new().embed(embed).components(components);
+
+        let response = channel
+            .create_message(&ctx, &builder, None)
+            .await
+            .wrap_err("Failed to create message")?
+            .model()
+            .await
+            .wrap_err("Failed to deserialize message")?;
+
+        Self::track(
+            ctx,
+            active_msg,
+            response.id,
+            response.channel_id,
+            Some(guild),
+        )
+        .await;
+
+        Ok(response.id)
+    }
+
+    /// Take over a message that was posted before, e.g. prior to a restart
+    pub async fn resume(
+        self,
+        ctx: Arc<Context>,
+        msg: Id<MessageMarker>,
+        channel: Id<ChannelMarker>,
+        guild: Id<GuildMarker>,
+    ) -> Result<()> {
+        let Self {
+            inner: mut active_msg,
+            ..
+        } = self;
+
+        let embed = active_msg
+            .build_page(&ctx)
+            .await
+            .wrap_err("Failed to build page")?;
+
+        let components = active_msg.build_components();
+        let builder = MessageBuilder::new().embed(embed).components(components);
+
+        if let Some(update_fut) = (msg, channel).update(&ctx, &builder, None) {
+            update_fut.await.wrap_err("Failed to update message")?;
+        }
+
+        Self::track(ctx, active_msg, msg, channel, Some(guild)).await;
 
         Ok(())
     }
 
-    #[allow(unused)]
+    async fn track(
+        ctx: Arc<Context>,
+        active_msg: ActiveMessage,
+        msg: Id<MessageMarker>,
+        channel: Id<ChannelMarker>,
+        guild: Option<Id<GuildMarker>>,
+    ) {
+        let (tx, rx) = watch::channel(active_msg.until_timeout());
+
+        Self::spawn_timeout(Arc::clone(&ctx), rx, msg, channel, guild);
+
+        let full = FullActiveMessage { active_msg, tx };
+        ctx.active_msgs.insert(msg, full).await;
+    }
+
     pub fn start_by_update(self, start_by_update: bool) -> Self {
         Self {
             start_by_update: Some(start_by_update),
@@ -98,24 +197,30 @@ impl ActiveMessagesBuilder {
 
     fn spawn_timeout(
         ctx: Arc<Context>,
-        mut rx: Receiver<()>,
+        mut rx: Receiver<Option<Duration>>,
         msg: Id<MessageMarker>,
         channel: Id<ChannelMarker>,
+        guild: Option<Id<GuildMarker>>,
     ) {
-        const MINUTE: Duration = Duration::from_secs(60);
-
         tokio::spawn(async move {
             loop {
+                let timeout = *rx.borrow_and_update();
+
+                let timeout_fut = async move {
+                    match timeout {
+                        Some(duration) => sleep(duration).await,
+                        None => future::pending().await,
+                    }
+                };
+
                 tokio::select! {
                     res = rx.changed() => if res.is_ok() {
                         continue
                     } else {
                         return
                     },
-                    _ = sleep(MINUTE) => {
-                        let pagination_active = ctx.active_msgs.remove(msg).await.is_some();
-
-                        if pagination_active  {
+                    _ = timeout_fut => {
+                        if let Some(removed) = ctx.active_msgs.remove(msg).await {
                             let builder = MessageBuilder::new().components(Vec::new());
 
                             if let Some(update_fut) = (msg, channel).update(&ctx, &builder, None) {
@@ -123,6 +228,11 @@ impl ActiveMessagesBuilder {
                                     warn!(?err, "Failed to remove components");
                                 }
                             }
+
+                            if let Some(draft) = removed.active_msg.draft() {
+                                let action = AuditAction::DraftExpired { draft };
+                                audit::record(&ctx, AuditEntry::new(guild, None, action)).await;
+                            }
                         }
 
                         return;
@@ -144,112 +254,177 @@ impl ActiveMessages {
     }
 
     pub async fn handle_component(ctx: &Context, mut component: InteractionComponent) {
+        let start = Instant::now();
+        let res = AssertUnwindSafe(Self::process_component(ctx, &mut component))
+            .catch_unwind()
+            .await
+            .unwrap_or_else(|payload| Err(panic_report(payload)));
+
+        let custom_id = &component.data.custom_id;
+        METRICS.active_handled("component", custom_id, start.elapsed());
+
+        if let Err(err) = res {
+            let incident = Incident::new();
+            let content = incident.user_message(&component.locale);
+
+            if let Err(err) = component.error_callback(ctx, content).await {
+                warn!(?err, "Failed to respond to failed component");
+            }
+
+            let origin = format!("component `{custom_id}`");
+            let user = component.user_id().ok();
+            incident.report(ctx, &origin, user, &err).await;
+        }
+    }
+
+    pub async fn handle_modal(ctx: &Context, mut modal: InteractionModal) {
+        let start = Instant::now();
+        let res = AssertUnwindSafe(Self::process_modal(ctx, &mut modal))
+            .catch_unwind()
+            .await
+            .unwrap_or_else(|payload| Err(panic_report(payload)));
+
+        let custom_id = &modal.data.custom_id;
+        METRICS.active_handled("modal", custom_id, start.elapsed());
+
+        if let Err(err) = res {
+            let incident = Incident::new();
+            let content = incident.user_message(&modal.locale);
+
+            if let Err(err) = modal.error_callback(ctx, content).await {
+                warn!(?err, "Failed to respond to failed modal");
+            }
+
+            let origin = format!("modal `{custom_id}`");
+            let user = modal.user_id().ok();
+            incident.report(ctx, &origin, user, &err).await;
+        }
+    }
+
+    /// Errors are returned only while the component is not acknowledged yet
+    async fn process_component(ctx: &Context, component: &mut InteractionComponent) -> Result<()> {
         let msg_id = component.message.id;
         let mut guard = ctx.active_msgs.inner.lock(&msg_id).await;
 
         let Some(FullActiveMessage { active_msg, tx }) = guard.get_mut() else {
-            return error!(
+            error!(
                 name = component.data.custom_id,
                 ?component,
                 "Unknown message component",
             );
+
+            return Ok(());
         };
 
-        match active_msg.handle_component(&mut component).await {
-            ComponentResult::CreateModal(modal) => {
-                if let Err(err) = component.modal(ctx, modal).await {
-                    return error!(?err, "Failed to create modal");
-                }
+        let res = match active_msg.handle_component(component).await {
+            ComponentResult::Replace(replacement) => {
+                *active_msg = *replacement;
 
-                let _ = tx.send(());
+                ComponentResult::BuildPage
             }
-            ComponentResult::BuildPage => match active_msg.build_page(ctx).await {
-                Ok(embed) => {
-                    let builder = MessageBuilder::new()
-                        .embed(embed)
-                        .components(active_msg.build_components());
-
-                    if let Err(err) = component.callback(ctx, builder).await {
-                        return error!(
-                            name = component.data.custom_id,
-                            ?err,
-                            "Failed to callback component",
-                        );
-                    }
+            res => res,
+        };
 
-                    let _ = tx.send(());
-                }
-                Err(err) => error!(
-                    name = component.data.custom_id,
-                    ?err,
-                    "Failed to build page for component",
-                ),
-            },
-            ComponentResult::Err(err) => {
-                error!(
-                    name = component.data.custom_id,
-                    ?err,
-                    "Failed to process component",
-                )
+        match res {
+            ComponentResult::CreateModal(modal) => {
+                component
+                    .modal(ctx, modal)
+                    .await
+                    .wrap_err("Failed to create modal")?;
+            }
+            ComponentResult::BuildPage => {
+                let embed = active_msg
+                    .build_page(ctx)
+                    .await
+                    .wrap_err("Failed to build page for component")?;
+
+                let builder = MessageBuilder::new()
+                    .embed(embed)
+                    .components(active_msg.build_components());
+
+                component
+                    .callback(ctx, builder)
+                    .await
+                    .wrap_err("Failed to callback component")?;
             }
+            ComponentResult::Err(err) => return Err(err.wrap_err("Failed to process component")),
+            ComponentResult::Replace(_) => unreachable!("replacements build a new page"),
         }
+
+        let _ = tx.send(active_msg.until_timeout());
+
+        Ok(())
     }
 
-    pub async fn handle_modal(ctx: &Context, mut modal: InteractionModal) {
+    /// Errors are returned only while the modal is not acknowledged yet
+    async fn process_modal(ctx: &Context, modal: &mut InteractionModal) -> Result<()> {
         let mut guard = match modal.message {
             Some(ref msg) => ctx.active_msgs.inner.own(msg.id).await,
-            None => return warn!("Received modal without message"),
+            None => {
+                warn!("Received modal without message");
+
+                return Ok(());
+            }
         };
 
         let Some(FullActiveMessage { active_msg, tx }) = guard.get_mut() else {
-            return error!(name = modal.data.custom_id, ?modal, "Unknown modal");
+            error!(name = modal.data.custom_id, ?modal, "Unknown modal");
+
+            return Ok(());
         };
 
-        if let Err(err) = active_msg.handle_modal(&mut modal).await {
-            return error!(name = modal.data.custom_id, ?err, "Failed to process modal");
-        }
+        active_msg
+            .handle_modal(modal)
+            .await
+            .wrap_err("Failed to process modal")?;
 
-        match active_msg.build_page(ctx).await {
-            Ok(embed) => {
-                let builder = MessageBuilder::new()
-                    .embed(embed)
-                    .components(active_msg.build_components());
+        let embed = active_msg
+            .build_page(ctx)
+            .await
+            .wrap_err("Failed to build page for modal")?;
 
-                if let Err(err) = modal.callback(ctx, builder).await {
-                    return error!(
-                        name = modal.data.custom_id,
-                        ?err,
-                        "Failed to callback modal",
-                    );
-                }
+        let builder = MessageBuilder::new()
+            .embed(embed)
+            .components(active_msg.build_components());
 
-                let _ = tx.send(());
-            }
-            Err(err) => error!(
-                name = modal.data.custom_id,
-                ?err,
-                "Failed to build page for modal",
-            ),
-        }
+        modal
+            .callback(ctx, builder)
+            .await
+            .wrap_err("Failed to callback modal")?;
+
+        let _ = tx.send(active_msg.until_timeout());
+
+        Ok(())
     }
 
     async fn remove(&self, msg: Id<MessageMarker>) -> Option<FullActiveMessage> {
-        self.inner.lock(&msg).await.remove()
+        let removed = self.inner.lock(&msg).await.remove();
+
+        if removed.is_some() {
+            METRICS.active_message_removed();
+        }
+
+        removed
     }
 
     async fn insert(&self, msg: Id<MessageMarker>, active_msg: FullActiveMessage) {
         self.inner.own(msg).await.insert(active_msg);
+        METRICS.active_message_added();
     }
 }
 
 struct FullActiveMessage {
     active_msg: ActiveMessage,
-    tx: Sender<()>,
+    tx: Sender<Option<Duration>>,
 }
 
 #[enum_dispatch(IActiveMessage)]
 pub enum ActiveMessage {
+    ConfirmAction,
     CreateIssue,
+    IssueComment,
+    Pagination,
+    ReportReview,
 }
 
 #[enum_dispatch]
@@ -268,10 +443,26 @@ pub trait IActiveMessage {
     fn handle_modal(&mut self, _modal: &mut InteractionModal) -> BoxFuture<'_, Result<()>> {
         Box::pin(future::ready(Ok(())))
     }
+
+    /// Duration of inactivity after which the components are removed.
+    ///
+    /// `None` keeps the message active until the bot shuts down.
+    fn until_timeout(&self) -> Option<Duration> {
+        Some(Duration::from_secs(60))
+    }
+
+    /// Description of the unfinished work, e.g. an issue that was never created.
+    ///
+    /// Recorded in the audit log if the message times out.
+    fn draft(&self) -> Option<String> {
+        None
+    }
 }
 
 pub enum ComponentResult {
     CreateModal(ModalBuilder),
     BuildPage,
+    /// Continue with a different active message on the same discord message
+    Replace(Box<ActiveMessage>),
     Err(Report),
 }
@@ -0,0 +1,112 @@
+use std::{collections::HashMap, fmt::Write};
+
+use eyre::{Result, WrapErr};
+use futures::future::BoxFuture;
+use octocrab::models::{issues::Issue, IssueState};
+
+use crate::{
+    core::Context,
+    util::{builder::EmbedBuilder, locale::t},
+};
+
+use super::{IPagination, Pages};
+
+/// Github only provides the first 1000 results of a search
+const MAX_RESULTS: usize = 1000;
+
+pub struct IssueSearchPagination {
+    query: String,
+    pages: HashMap<usize, Vec<Issue>>,
+}
+
+impl IssueSearchPagination {
+    /// Search for the first page of issues.
+    ///
+    /// Returns the pagination alongside the total amount of results.
+    pub async fn new(ctx: &Context, query: String, per_page: usize) -> Result<(Self, usize)> {
+        let (issues, total) = Self::fetch(ctx, &query, 0, per_page).await?;
+
+        let mut pages = HashMap::new();
+        pages.insert(0, issues);
+
+        Ok((Self { query, pages }, total))
+    }
+
+    async fn fetch(
+        ctx: &Context,
+        query: &str,
+        index: usize,
+        per_page: usize,
+    ) -> Result<(Vec<Issue>, usize)> {
+        let page = ctx
+            .github
+            .search()
+            .issues_and_pull_requests(query)
+            .per_page(per_page as u8)
+            .page(index as u32 + 1)
+            .send()
+            .await
+            .wrap_err("Failed to search issues")?;
+
+        let total = page.total_count.unwrap_or(0) as usize;
+
+        Ok((page.items, total.min(MAX_RESULTS)))
+    }
+}
+
+impl IPagination for IssueSearchPagination {
+    fn build_page<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        pages: &'a Pages,
+        locale: &'a str,
+    ) -> BoxFuture<'a, Result<EmbedBuilder>> {
+        let fut = async move {
+            if !self.pages.contains_key(&pages.index()) {
+                let (issues, _) =
+                    Self::fetch(ctx, &self.query, pages.index(), pages.per_page()).await?;
+
+                self.pages.insert(pages.index(), issues);
+            }
+
+            let mut description = String::new();
+
+            for issue in self.pages[&pages.index()].iter() {
+                let repo = issue
+                    .repository_url
+                    .path_segments()
+                    .and_then(|mut segments| segments.next_back())
+                    .unwrap_or_default();
+
+                let state = match issue.state {
+                    IssueState::Open => t!(locale, "issue-state-open"),
+                    IssueState::Closed => t!(locale, "issue-state-closed"),
+                    _ => t!(locale, "issue-state-unknown"),
+                };
+
+                let author = t!(locale, "search-author", author = issue.user.login.as_str());
+
+                let _ = writeln!(
+                    description,
+                    "**[#{number} {title}]({url})**\n`{repo}` • {state} • {author} • <t:{created}:R>",
+                    number = issue.number,
+                    title = issue.title,
+                    url = issue.html_url,
+                    created = issue.created_at.timestamp(),
+                );
+            }
+
+            if description.is_empty() {
+                description.push_str(&t!(locale, "search-empty"));
+            }
+
+            let embed = EmbedBuilder::new()
+                .title(t!(locale, "search-title"))
+                .description(description);
+
+            Ok(embed)
+        };
+
+        Box::pin(fut)
+    }
+}
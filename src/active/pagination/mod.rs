@@ -0,0 +1,223 @@
+use enum_dispatch::enum_dispatch;
+use eyre::{ContextCompat, Result};
+use futures::future::{self, BoxFuture};
+use twilight_model::channel::message::{
+    component::{ActionRow, Button, ButtonStyle},
+    Component,
+};
+
+use crate::{
+    core::Context,
+    util::{
+        builder::{EmbedBuilder, ModalBuilder, TextInputBuilder},
+        interaction::{InteractionComponent, InteractionModal},
+        locale::t,
+    },
+};
+
+use super::{ComponentResult, IActiveMessage};
+
+pub use self::{issue_search::*, user_reports::*};
+
+mod issue_search;
+mod user_reports;
+
+/// Active message to flip through pages of some kind
+pub struct Pagination {
+    kind: PaginationKind,
+    pages: Pages,
+    locale: String,
+}
+
+impl Pagination {
+    /// `amount` is the total amount of entries, each page shows `per_page` of them
+    pub fn new(
+        kind: impl Into<PaginationKind>,
+        amount: usize,
+        per_page: usize,
+        locale: &str,
+    ) -> Self {
+        Self {
+            kind: kind.into(),
+            pages: Pages::new(amount, per_page),
+            locale: locale.to_owned(),
+        }
+    }
+}
+
+impl IActiveMessage for Pagination {
+    fn build_page<'a>(&'a mut self, ctx: &'a Context) -> BoxFuture<'a, Result<EmbedBuilder>> {
+        let fut = async move {
+            let embed = self.kind.build_page(ctx, &self.pages, &self.locale).await?;
+            let footer = t!(
+                &self.locale,
+                "pagination-footer",
+                page = self.pages.curr_page(),
+                last = self.pages.last_page(),
+            );
+
+            Ok(embed.footer(footer))
+        };
+
+        Box::pin(fut)
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if self.pages.last_index == 0 {
+            return Vec::new();
+        }
+
+        let at_start = self.pages.index == 0;
+        let at_end = self.pages.index == self.pages.last_index;
+
+        let button = |custom_id: &str, label: &str, disabled: bool| {
+            Component::Button(Button {
+                custom_id: Some(custom_id.to_owned()),
+                disabled,
+                emoji: None,
+                label: Some(label.to_owned()),
+                style: ButtonStyle::Secondary,
+                url: None,
+                sku_id: None,
+            })
+        };
+
+        let button_row = ActionRow {
+            components: vec![
+                button("page_first", "⏮", at_start),
+                button("page_prev", "◀", at_start),
+                button("page_custom", "🔢", false),
+                button("page_next", "▶", at_end),
+                button("page_last", "⏭", at_end),
+            ],
+        };
+
+        vec![Component::ActionRow(button_row)]
+    }
+
+    fn handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> BoxFuture<'static, ComponentResult> {
+        let pages = &mut self.pages;
+
+        let res = match component.data.custom_id.as_str() {
+            "page_first" => {
+                pages.index = 0;
+
+                ComponentResult::BuildPage
+            }
+            "page_prev" => {
+                pages.index = pages.index.saturating_sub(1);
+
+                ComponentResult::BuildPage
+            }
+            "page_next" => {
+                pages.index = (pages.index + 1).min(pages.last_index);
+
+                ComponentResult::BuildPage
+            }
+            "page_last" => {
+                pages.index = pages.last_index;
+
+                ComponentResult::BuildPage
+            }
+            "page_custom" => {
+                let locale = &self.locale;
+                let placeholder = t!(locale, "pagination-placeholder", last = pages.last_page());
+
+                let input = TextInputBuilder::new("page", t!(locale, "pagination-input"))
+                    .required(true)
+                    .min_len(1)
+                    .max_len(5)
+                    .placeholder(placeholder);
+
+                let modal =
+                    ModalBuilder::new("page_custom", t!(locale, "pagination-modal")).input(input);
+
+                ComponentResult::CreateModal(modal)
+            }
+            other => ComponentResult::Err(eyre!("Unknown component `{other}`")),
+        };
+
+        Box::pin(future::ready(res))
+    }
+
+    fn handle_modal(&mut self, modal: &mut InteractionModal) -> BoxFuture<'static, Result<()>> {
+        fn inner(pages: &mut Pages, modal: &mut InteractionModal) -> Result<()> {
+            if modal.data.custom_id != "page_custom" {
+                bail!("Unknown modal `{}`", modal.data.custom_id);
+            }
+
+            let input = modal
+                .data
+                .components
+                .first()
+                .and_then(|row| row.components.first())
+                .and_then(|component| component.value.as_deref())
+                .wrap_err("Missing modal input")?;
+
+            // Invalid input is a typo of the user, not an error, so it keeps the current page
+            if let Ok(page) = input.trim().parse::<i64>() {
+                let page = page.clamp(1, pages.last_page() as i64) as usize;
+                pages.index = page - 1;
+            }
+
+            Ok(())
+        }
+
+        Box::pin(future::ready(inner(&mut self.pages, modal)))
+    }
+}
+
+/// Keeps track of the current page
+pub struct Pages {
+    index: usize,
+    last_index: usize,
+    per_page: usize,
+}
+
+impl Pages {
+    fn new(amount: usize, per_page: usize) -> Self {
+        Self {
+            index: 0,
+            last_index: amount.saturating_sub(1) / per_page,
+            per_page,
+        }
+    }
+
+    /// Zero-based index of the current page
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    pub fn per_page(&self) -> usize {
+        self.per_page
+    }
+
+    /// One-based number of the current page
+    pub fn curr_page(&self) -> usize {
+        self.index + 1
+    }
+
+    /// One-based number of the last page
+    pub fn last_page(&self) -> usize {
+        self.last_index + 1
+    }
+}
+
+#[enum_dispatch(IPagination)]
+pub enum PaginationKind {
+    IssueSearchPagination,
+    UserReportsPagination,
+}
+
+#[enum_dispatch]
+pub trait IPagination {
+    fn build_page<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        pages: &'a Pages,
+        locale: &'a str,
+    ) -> BoxFuture<'a, Result<EmbedBuilder>>;
+}
@@ -0,0 +1,235 @@
+use std::fmt::Write;
+
+use eyre::{Result, WrapErr};
+use futures::future::BoxFuture;
+use octocrab::models::{issues::Issue, IssueState};
+use time::OffsetDateTime;
+use twilight_model::id::{marker::UserMarker, Id};
+
+use crate::{
+    core::{
+        metrics::{Api, ObserveExt},
+        Context,
+    },
+    util::{
+        builder::EmbedBuilder,
+        config::Project,
+        github::{github_time, IssueRef},
+        locale::t,
+    },
+};
+
+use super::{IPagination, Pages};
+
+/// Issues that were created from messages of a discord user
+pub struct UserReportsPagination {
+    user: Id<UserMarker>,
+    name: Option<String>,
+    entries: Vec<UserReport>,
+}
+
+struct UserReport {
+    issue: IssueRef,
+    url: String,
+    created_at: OffsetDateTime,
+    /// Title and state; fetched once the entry is shown
+    details: Option<(String, IssueState)>,
+}
+
+impl UserReportsPagination {
+    /// Collect the issue records of the user and, as a fallback for issues without record,
+    /// search the project's repositories for issues whose body mentions the user as author.
+    pub async fn new(
+        ctx: &Context,
+        project: &Project,
+        user: Id<UserMarker>,
+        name: Option<&str>,
+    ) -> Result<Self> {
+        let github = &project.github_config;
+
+        let mut entries: Vec<_> = ctx
+            .records
+            .by_author(user)
+            .await
+            .into_iter()
+            .filter(|record| {
+                record.owner == github.owner
+                    && github
+                        .repositories
+                        .values()
+                        .any(|repo| *repo == record.repo)
+            })
+            .map(|record| UserReport {
+                issue: record.issue_ref(),
+                url: record.url,
+                created_at: record.created_at,
+                details: None,
+            })
+            .collect();
+
+        if let Some(name) = name {
+            match Self::search(ctx, project, name).await {
+                Ok(issues) => {
+                    for issue in issues {
+                        let Some(repo) = repo_name(&issue) else {
+                            continue;
+                        };
+
+                        let known = entries.iter().any(|entry| {
+                            entry.issue.number == issue.number && entry.issue.repo == repo
+                        });
+
+                        if known {
+                            continue;
+                        }
+
+                        entries.push(UserReport {
+                            issue: IssueRef {
+                                owner: github.owner.clone(),
+                                repo: repo.to_owned(),
+                                number: issue.number,
+                            },
+                            url: issue.html_url.to_string(),
+                            created_at: github_time(issue.created_at.timestamp()),
+                            details: Some((issue.title, issue.state)),
+                        });
+                    }
+                }
+                Err(err) => warn!(?err, "Failed to search issues of user"),
+            }
+        }
+
+        entries.sort_unstable_by(|a, b| b.created_at.cmp(&a.created_at));
+
+        Ok(Self {
+            user,
+            name: name.map(str::to_owned),
+            entries,
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Issues whose body contains the attribution that `issue_body` writes
+    async fn search(ctx: &Context, project: &Project, name: &str) -> Result<Vec<Issue>> {
+        let github = &project.github_config;
+        let attribution = format!("Original message by @{name}");
+
+        // Quotes would end the phrase early; the search ignores punctuation anyway
+        let phrase = attribution.replace('"', "");
+        let mut query = format!("\"{phrase}\" in:body is:issue");
+
+        for repo in github.repositories.values() {
+            let _ = write!(query, " repo:{}/{repo}", github.owner);
+        }
+
+        let page = ctx
+            .github
+            .search()
+            .issues_and_pull_requests(&query)
+            .per_page(100)
+            .send()
+            .observe(Api::Github, "search.issues")
+            .await
+            .wrap_err("Failed to search issues")?;
+
+        // The search ignores punctuation so make sure the attribution matches exactly
+        let attribution = format!("[{attribution}](");
+
+        let issues = page
+            .items
+            .into_iter()
+            .filter(|issue| {
+                issue
+                    .body
+                    .as_deref()
+                    .is_some_and(|body| body.contains(&attribution))
+            })
+            .collect();
+
+        Ok(issues)
+    }
+}
+
+impl IPagination for UserReportsPagination {
+    fn build_page<'a>(
+        &'a mut self,
+        ctx: &'a Context,
+        pages: &'a Pages,
+        locale: &'a str,
+    ) -> BoxFuture<'a, Result<EmbedBuilder>> {
+        let fut = async move {
+            let start = pages.index() * pages.per_page();
+            let end = (start + pages.per_page()).min(self.entries.len());
+            let mut description = String::new();
+
+            for entry in self.entries[start..end].iter_mut() {
+                if entry.details.is_none() {
+                    let IssueRef {
+                        owner,
+                        repo,
+                        number,
+                    } = &entry.issue;
+
+                    match ctx
+                        .github
+                        .issues(owner, repo)
+                        .get(*number)
+                        .observe(Api::Github, "issues.get")
+                        .await
+                    {
+                        Ok(issue) => entry.details = Some((issue.title, issue.state)),
+                        Err(err) => warn!(?err, issue = %entry.issue, "Failed to request issue"),
+                    }
+                }
+
+                let (title, state) = match entry.details {
+                    Some((ref title, IssueState::Open)) => {
+                        (title.as_str(), t!(locale, "issue-state-open"))
+                    }
+                    Some((ref title, IssueState::Closed)) => {
+                        (title.as_str(), t!(locale, "issue-state-closed"))
+                    }
+                    Some((ref title, _)) => (title.as_str(), t!(locale, "issue-state-unknown")),
+                    None => ("", t!(locale, "issue-state-unknown")),
+                };
+
+                let _ = writeln!(
+                    description,
+                    "**[#{number} {title}]({url})**\n`{repo}` • {state} • <t:{created}:R>",
+                    number = entry.issue.number,
+                    url = entry.url,
+                    repo = entry.issue.repo,
+                    created = entry.created_at.unix_timestamp(),
+                );
+            }
+
+            let title = match self.name {
+                Some(ref name) => t!(locale, "user-reports-title", name = name.as_str()),
+                None => t!(
+                    locale,
+                    "user-reports-title-id",
+                    user = self.user.to_string()
+                ),
+            };
+
+            Ok(EmbedBuilder::new().title(title).description(description))
+        };
+
+        Box::pin(fut)
+    }
+}
+
+/// Name of the repository that the issue belongs to
+fn repo_name(issue: &Issue) -> Option<&str> {
+    issue
+        .repository_url
+        .path_segments()
+        .and_then(|mut segments| segments.next_back())
+}
@@ -0,0 +1,630 @@
+use std::{fmt::Write, mem, sync::Arc, time::Duration};
+
+use eyre::{ContextCompat, Result, WrapErr};
+use futures::future::{self, BoxFuture};
+use serde::{Deserialize, Serialize};
+use twilight_model::{
+    channel::message::{
+        component::{ActionRow, Button, ButtonStyle, TextInputStyle},
+        embed::EmbedField,
+        Component,
+    },
+    id::{
+        marker::{GuildMarker, MessageMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{
+    core::{
+        audit::{self, AuditAction, AuditEntry, Operator},
+        metrics::{Api, ObserveExt},
+        reports::PendingReports,
+        Context,
+    },
+    util::{
+        builder::{EmbedBuilder, MessageBuilder, ModalBuilder, TextInputBuilder},
+        config::Project,
+        constants::{DARK_GREEN, RED},
+        ext::{Authored, ChannelExt},
+        github::IssueRef,
+        interaction::{InteractionComponent, InteractionModal},
+        locale::{default_locale, t, DEFAULT_LOCALE},
+    },
+};
+
+use super::{ActiveMessages, ComponentResult, CreateIssue, IActiveMessage};
+
+/// Custom id of the modal that is opened by `/report`
+pub const REPORT_MODAL: &str = "report";
+
+/// A report that was submitted by a member through `/report`
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ReportSubmission {
+    pub reporter_id: Id<UserMarker>,
+    pub reporter_name: String,
+    pub title: String,
+    pub description: String,
+    pub steps: Option<String>,
+    pub version: Option<String>,
+    /// Locale of the reporter for the notification about the review
+    #[serde(default = "default_locale")]
+    pub locale: String,
+}
+
+impl ReportSubmission {
+    /// Modal to submit a new report or, if given, edit an existing one
+    pub fn modal(
+        custom_id: &str,
+        title: &str,
+        prefill: Option<&Self>,
+        locale: &str,
+    ) -> ModalBuilder {
+        let mut title_input = TextInputBuilder::new("title", t!(locale, "reports-title"))
+            .required(true)
+            .max_len(64);
+
+        let mut description =
+            TextInputBuilder::new("description", t!(locale, "reports-description"))
+                .required(true)
+                .style(TextInputStyle::Paragraph)
+                .max_len(2000)
+                .placeholder(t!(locale, "reports-description-placeholder"));
+
+        let mut steps = TextInputBuilder::new("steps", t!(locale, "reports-steps"))
+            .required(false)
+            .style(TextInputStyle::Paragraph)
+            .max_len(1000);
+
+        let mut version = TextInputBuilder::new("version", t!(locale, "reports-version"))
+            .required(false)
+            .max_len(32);
+
+        if let Some(report) = prefill {
+            title_input = title_input.value(&report.title);
+            description = description.value(&report.description);
+
+            if let Some(ref value) = report.steps {
+                steps = steps.value(value);
+            }
+
+            if let Some(ref value) = report.version {
+                version = version.value(value);
+            }
+        }
+
+        ModalBuilder::new(custom_id, title)
+            .input(title_input)
+            .input(description)
+            .input(steps)
+            .input(version)
+    }
+
+    pub fn from_modal(modal: &mut InteractionModal) -> Result<Self> {
+        let user = modal.user()?;
+
+        let mut report = Self {
+            reporter_id: user.id,
+            reporter_name: user.name.clone(),
+            title: String::new(),
+            description: String::new(),
+            steps: None,
+            version: None,
+            locale: modal.locale.clone(),
+        };
+
+        report.update(modal)?;
+
+        Ok(report)
+    }
+
+    /// Overwrite the fields with the inputs of a submitted report modal
+    fn update(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        let inputs = modal
+            .data
+            .components
+            .iter_mut()
+            .flat_map(|row| row.components.iter_mut());
+
+        for input in inputs {
+            let value = input.value.take().filter(|value| !value.is_empty());
+
+            match input.custom_id.as_str() {
+                "title" => self.title = value.wrap_err("Missing report title")?,
+                "description" => self.description = value.wrap_err("Missing report description")?,
+                "steps" => self.steps = value,
+                "version" => self.version = value,
+                other => warn!(?other, "Unknown report input"),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Issue content composed of all parts of the report
+    pub fn content(&self) -> String {
+        let mut content = self.description.clone();
+
+        if let Some(ref steps) = self.steps {
+            let _ = write!(content, "\n\n**Steps to reproduce**\n{steps}");
+        }
+
+        if let Some(ref version) = self.version {
+            let _ = write!(content, "\n\n**Version:** {version}");
+        }
+
+        content
+    }
+}
+
+pub struct ReportReview {
+    project: Arc<Project>,
+    report: ReportSubmission,
+    pending: Arc<PendingReports>,
+    status: ReviewStatus,
+    /// Shown once on the next page, e.g. for invalid inputs
+    notice: Option<String>,
+    /// Locale of the reviewer that interacted last
+    locale: String,
+    guild: Option<Id<GuildMarker>>,
+    /// User that rejected the report or marked it as duplicate
+    operator: Option<Operator>,
+}
+
+enum ReviewStatus {
+    Pending,
+    Rejecting { reason: String, reviewer: String },
+    Rejected { reason: String, reviewer: String },
+    MarkingDuplicate { issue: IssueRef, reviewer: String },
+    Duplicate { issue: IssueRef, reviewer: String },
+}
+
+impl ReportReview {
+    pub fn new(
+        report: ReportSubmission,
+        project: Arc<Project>,
+        pending: Arc<PendingReports>,
+    ) -> Self {
+        Self {
+            project,
+            report,
+            pending,
+            status: ReviewStatus::Pending,
+            notice: None,
+            locale: DEFAULT_LOCALE.to_owned(),
+            guild: None,
+            operator: None,
+        }
+    }
+
+    /// Pick up the queue messages of all reports that were not reviewed before the last shutdown
+    pub async fn resume_pending(ctx: Arc<Context>) {
+        for pending in ctx.reports.all().await {
+            let Some(project) = ctx.project(Some(pending.guild)) else {
+                warn!(guild = %pending.guild, "Missing project of pending report");
+
+                continue;
+            };
+
+            let review = Self::new(pending.report, project, Arc::clone(&ctx.reports));
+
+            let res = ActiveMessages::builder(review)
+                .resume(
+                    Arc::clone(&ctx),
+                    pending.message,
+                    pending.channel,
+                    pending.guild,
+                )
+                .await;
+
+            if let Err(err) = res {
+                warn!(msg = %pending.message, ?err, "Failed to resume pending report");
+            }
+        }
+    }
+
+    /// Keep the stored report in sync with the review of the queue message
+    async fn persist(&self, msg: Id<MessageMarker>) {
+        let res = match self.status {
+            ReviewStatus::Pending => self.pending.update(msg, &self.report).await,
+            _ => self.pending.remove(msg).await,
+        };
+
+        if let Err(err) = res {
+            warn!(?err, "Failed to store pending report");
+        }
+    }
+
+    fn audit(&self, action: AuditAction) -> AuditEntry {
+        AuditEntry::new(self.guild, self.operator.clone(), action)
+    }
+
+    /// Let the reporter know about the outcome of the review
+    async fn notify_reporter(&self, ctx: &Context, embed: EmbedBuilder) -> Result<()> {
+        let channel = ctx
+            .http
+            .create_private_channel(self.report.reporter_id)
+            .observe(Api::Discord, "create_private_channel")
+            .await
+            .wrap_err("Failed to create private channel")?
+            .model()
+            .await
+            .wrap_err("Failed to deserialize private channel")?;
+
+        channel
+            .id
+            .create_message(ctx, &MessageBuilder::new().embed(embed), None)
+            .await
+            .wrap_err("Failed to create private message")?;
+
+        Ok(())
+    }
+
+    async fn reject(&mut self, ctx: &Context, reason: String, reviewer: String) {
+        let locale = &self.report.locale;
+
+        let embed = EmbedBuilder::new()
+            .title(t!(
+                locale,
+                "reports-rejected-title",
+                title = self.report.title.as_str()
+            ))
+            .description(t!(
+                locale,
+                "reports-rejected-reason",
+                reason = reason.as_str()
+            ))
+            .color(RED);
+
+        if let Err(err) = self.notify_reporter(ctx, embed).await {
+            warn!(?err, "Failed to notify reporter about rejection");
+        }
+
+        let action = AuditAction::ReportRejected {
+            reporter: self.report.reporter_id,
+            title: self.report.title.clone(),
+            reason: reason.clone(),
+        };
+
+        audit::record(ctx, self.audit(action)).await;
+
+        self.status = ReviewStatus::Rejected { reason, reviewer };
+    }
+
+    async fn mark_duplicate(&mut self, ctx: &Context, issue: IssueRef, reviewer: String) {
+        let locale = &self.report.locale;
+
+        let embed = EmbedBuilder::new()
+            .title(t!(
+                locale,
+                "reports-duplicate-title",
+                title = self.report.title.as_str()
+            ))
+            .description(t!(
+                locale,
+                "reports-duplicate-tracked",
+                issue = format!("[{issue}]({})", issue.url())
+            ));
+
+        if let Err(err) = self.notify_reporter(ctx, embed).await {
+            warn!(?err, "Failed to notify reporter about duplicate");
+        }
+
+        let action = AuditAction::ReportMarkedDuplicate {
+            reporter: self.report.reporter_id,
+            title: self.report.title.clone(),
+            duplicate_of: issue.url(),
+        };
+
+        audit::record(ctx, self.audit(action)).await;
+
+        self.status = ReviewStatus::Duplicate { issue, reviewer };
+    }
+}
+
+impl IActiveMessage for ReportReview {
+    fn build_page<'a>(&'a mut self, ctx: &'a Context) -> BoxFuture<'a, Result<EmbedBuilder>> {
+        match mem::replace(&mut self.status, ReviewStatus::Pending) {
+            ReviewStatus::Rejecting { reason, reviewer } => {
+                let fut = async move {
+                    self.reject(ctx, reason, reviewer).await;
+
+                    self.build_page(ctx).await
+                };
+
+                return Box::pin(fut);
+            }
+            ReviewStatus::MarkingDuplicate { issue, reviewer } => {
+                let fut = async move {
+                    self.mark_duplicate(ctx, issue, reviewer).await;
+
+                    self.build_page(ctx).await
+                };
+
+                return Box::pin(fut);
+            }
+            status => self.status = status,
+        }
+
+        let report = &self.report;
+        let locale = &self.locale;
+
+        let mut fields = vec![EmbedField {
+            inline: true,
+            name: t!(locale, "reports-reporter"),
+            value: format!("<@{}>", report.reporter_id),
+        }];
+
+        if let Some(ref version) = report.version {
+            fields.push(EmbedField {
+                inline: true,
+                name: t!(locale, "reports-version"),
+                value: version.to_owned(),
+            });
+        }
+
+        if let Some(ref steps) = report.steps {
+            fields.push(EmbedField {
+                inline: false,
+                name: t!(locale, "reports-steps"),
+                value: steps.to_owned(),
+            });
+        }
+
+        let outcome = match self.status {
+            ReviewStatus::Rejected {
+                ref reason,
+                ref reviewer,
+            } => Some(t!(
+                locale,
+                "reports-outcome-rejected",
+                reviewer = reviewer.as_str(),
+                reason = reason.as_str(),
+            )),
+            ReviewStatus::Duplicate {
+                ref issue,
+                ref reviewer,
+            } => Some(t!(
+                locale,
+                "reports-outcome-duplicate",
+                issue = format!("[{issue}]({})", issue.url()),
+                reviewer = reviewer.as_str(),
+            )),
+            _ => None,
+        };
+
+        if let Some(value) = outcome {
+            fields.push(EmbedField {
+                inline: false,
+                name: t!(locale, "reports-outcome"),
+                value,
+            });
+        }
+
+        if let Some(notice) = self.notice.take() {
+            fields.push(EmbedField {
+                inline: false,
+                name: t!(locale, "reports-notice"),
+                value: notice,
+            });
+        }
+
+        let color = match self.status {
+            ReviewStatus::Pending => DARK_GREEN,
+            _ => RED,
+        };
+
+        let embed = EmbedBuilder::new()
+            .title(t!(
+                locale,
+                "reports-title-prefix",
+                title = report.title.as_str()
+            ))
+            .description(&report.description)
+            .fields(fields)
+            .color(color);
+
+        Box::pin(future::ready(Ok(embed)))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        if !matches!(self.status, ReviewStatus::Pending) {
+            return Vec::new();
+        }
+
+        let accept = Button {
+            custom_id: Some("report_accept".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some(t!(&self.locale, "reports-accept")),
+            style: ButtonStyle::Success,
+            url: None,
+            sku_id: None,
+        };
+
+        let edit = Button {
+            custom_id: Some("report_edit".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some(t!(&self.locale, "reports-edit")),
+            style: ButtonStyle::Primary,
+            url: None,
+            sku_id: None,
+        };
+
+        let reject = Button {
+            custom_id: Some("report_reject".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some(t!(&self.locale, "reports-reject")),
+            style: ButtonStyle::Danger,
+            url: None,
+            sku_id: None,
+        };
+
+        let duplicate = Button {
+            custom_id: Some("report_duplicate".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some(t!(&self.locale, "reports-duplicate")),
+            style: ButtonStyle::Secondary,
+            url: None,
+            sku_id: None,
+        };
+
+        let button_row = ActionRow {
+            components: vec![
+                Component::Button(accept),
+                Component::Button(edit),
+                Component::Button(reject),
+                Component::Button(duplicate),
+            ],
+        };
+
+        vec![Component::ActionRow(button_row)]
+    }
+
+    fn handle_component(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> BoxFuture<'static, ComponentResult> {
+        self.locale.clone_from(&component.locale);
+        let locale = &self.locale;
+
+        let res = match component.data.custom_id.as_str() {
+            "report_accept" => match component.guild_id {
+                Some(guild) => {
+                    let create = CreateIssue::from_report(
+                        &self.report,
+                        &component.message,
+                        guild,
+                        Arc::clone(&self.project),
+                        &component.locale,
+                    );
+
+                    ComponentResult::Replace(Box::new(create.into()))
+                }
+                None => ComponentResult::Err(eyre!("Missing guild of report")),
+            },
+            "report_edit" => {
+                let title = t!(locale, "reports-edit-modal");
+                let modal =
+                    ReportSubmission::modal("report_edit", &title, Some(&self.report), locale);
+
+                ComponentResult::CreateModal(modal)
+            }
+            "report_reject" => {
+                let input = TextInputBuilder::new("reason", t!(locale, "reports-reason"))
+                    .required(true)
+                    .style(TextInputStyle::Paragraph)
+                    .max_len(1000);
+
+                let title = t!(locale, "reports-reject-modal");
+                let modal = ModalBuilder::new("report_reject", title).input(input);
+
+                ComponentResult::CreateModal(modal)
+            }
+            "report_duplicate" => {
+                let input = TextInputBuilder::new("issue", t!(locale, "reports-issue"))
+                    .required(true)
+                    .max_len(100)
+                    .placeholder(t!(locale, "reports-issue-placeholder"));
+
+                let title = t!(locale, "reports-duplicate-modal");
+                let modal = ModalBuilder::new("report_duplicate", title).input(input);
+
+                ComponentResult::CreateModal(modal)
+            }
+            other => ComponentResult::Err(eyre!("Unknown component `{other}`")),
+        };
+
+        Box::pin(future::ready(res))
+    }
+
+    fn handle_modal(&mut self, modal: &mut InteractionModal) -> BoxFuture<'_, Result<()>> {
+        fn inner(this: &mut ReportReview, modal: &mut InteractionModal) -> Result<()> {
+            this.locale.clone_from(&modal.locale);
+
+            if modal.data.custom_id == "report_edit" {
+                return this.report.update(modal);
+            }
+
+            let reviewer = modal.username()?.to_owned();
+            this.guild = modal.guild_id;
+            this.operator = modal.user().ok().map(Operator::from);
+
+            let value = modal
+                .data
+                .components
+                .first_mut()
+                .and_then(|row| row.components.first_mut())
+                .and_then(|component| component.value.take())
+                .wrap_err("Missing modal input")?;
+
+            match modal.data.custom_id.as_str() {
+                "report_reject" => {
+                    this.status = ReviewStatus::Rejecting {
+                        reason: value,
+                        reviewer,
+                    };
+                }
+                "report_duplicate" => {
+                    let parsed = value
+                        .trim()
+                        .split_once('#')
+                        .and_then(|(repo, number)| Some((repo, number.parse().ok()?)));
+
+                    match parsed {
+                        Some((repo, number)) => {
+                            match IssueRef::resolve(&this.project, repo, number) {
+                                Some(issue) => {
+                                    this.status =
+                                        ReviewStatus::MarkingDuplicate { issue, reviewer };
+                                }
+                                None => {
+                                    let notice =
+                                        t!(&this.locale, "issue-unknown-repository", repo = repo);
+                                    this.notice = Some(notice);
+                                }
+                            }
+                        }
+                        None => {
+                            let notice = t!(
+                                &this.locale,
+                                "reports-invalid-issue",
+                                value = value.as_str()
+                            );
+                            this.notice = Some(notice);
+                        }
+                    }
+                }
+                other => bail!("Unknown modal `{other}`"),
+            }
+
+            Ok(())
+        }
+
+        let msg = modal.message.as_ref().map(|msg| msg.id);
+        let res = inner(self, modal);
+
+        let fut = async move {
+            res?;
+
+            if let Some(msg) = msg {
+                self.persist(msg).await;
+            }
+
+            Ok(())
+        };
+
+        Box::pin(fut)
+    }
+
+    fn until_timeout(&self) -> Option<Duration> {
+        // Reports wait in the queue until an operator reviews them
+        match self.status {
+            ReviewStatus::Pending => None,
+            _ => Some(Duration::from_secs(60)),
+        }
+    }
+}
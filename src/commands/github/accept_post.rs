@@ -0,0 +1,103 @@
+use std::sync::Arc;
+
+use command_macros::SlashCommand;
+use eyre::{Result, WrapErr};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::{
+        audit::Operator,
+        forum,
+        metrics::{Api, ObserveExt},
+        records::ThreadReservation,
+        Context,
+    },
+    util::{
+        builder::{EmbedBuilder, MessageBuilder},
+        ext::{Authored, InteractionCommandExt},
+        interaction::InteractionCommand,
+        locale::t,
+    },
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "accept-post")]
+#[flags(AUTHORITY, ONLY_GUILDS)]
+/// Create a github issue from this forum post
+pub struct AcceptPost;
+
+async fn slash_acceptpost(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    let project = ctx.project(command.guild_id);
+
+    let Some(forum) = project.as_ref().and_then(|project| project.forum.as_ref()) else {
+        command
+            .error(&ctx, t!(&command.locale, "forum-not-configured"))
+            .await?;
+
+        return Ok(());
+    };
+
+    let post = ctx
+        .http
+        .channel(command.channel_id)
+        .observe(Api::Discord, "channel")
+        .await
+        .wrap_err("Failed to request channel")?
+        .model()
+        .await
+        .wrap_err("Failed to deserialize channel")?;
+
+    if post.parent_id != Some(forum.channel_id_as_marker()) {
+        let content = t!(
+            &command.locale,
+            "forum-not-a-post",
+            channel = forum.channel_id.to_string()
+        );
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let reserved = match ctx.records.reserve_thread(post.id).await {
+        ThreadReservation::Reserved(reserved) => reserved,
+        ThreadReservation::Existing(record) => {
+            let content = t!(&command.locale, "forum-post-has-issue", url = record.url);
+            command.error(&ctx, content).await?;
+
+            return Ok(());
+        }
+        ThreadReservation::InProgress => {
+            command
+                .error(&ctx, t!(&command.locale, "forum-post-in-progress"))
+                .await?;
+
+            return Ok(());
+        }
+    };
+
+    let operator = command.user().ok().map(Operator::from);
+
+    let record =
+        match forum::create_issue_from_post(&ctx, &post, operator, &command.locale, reserved).await
+        {
+            Ok(record) => record,
+            Err(err) => {
+                let _ = command
+                    .error(&ctx, t!(&command.locale, "forum-issue-failed"))
+                    .await;
+
+                return Err(err);
+            }
+        };
+
+    let embed = EmbedBuilder::new()
+        .title(record.issue_ref().to_string())
+        .url(record.url.clone())
+        .description(t!(&command.locale, "forum-issue-created"));
+
+    command
+        .update(&ctx, &MessageBuilder::new().embed(embed))
+        .await?;
+
+    Ok(())
+}
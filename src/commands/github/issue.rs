@@ -0,0 +1,537 @@
+use std::{collections::BTreeSet, fmt::Write, sync::Arc};
+
+use command_macros::SlashCommand;
+use eyre::{ContextCompat, Result, WrapErr};
+use octocrab::{models::issues::IssueStateReason, params::LockReason as GithubLockReason};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::application::command::{CommandOptionChoice, CommandOptionChoiceValue};
+
+use crate::{
+    active::{
+        ActiveMessages, ConfirmAction, IssueComment as IssueCommentActive, IssueSearchPagination,
+        Pagination,
+    },
+    core::{
+        audit::{self, AuditEntry, Operator},
+        commands::slash::AutocompleteInput,
+        Context,
+    },
+    util::{
+        builder::MessageBuilder,
+        ext::{Authored, InteractionCommandExt},
+        github::{repo_labels, search_value, IssueAction, IssueRef},
+        interaction::InteractionCommand,
+        locale::t,
+    },
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "issue")]
+#[flags(AUTHORITY, ONLY_GUILDS)]
+#[autocomplete]
+/// Manage github issues
+pub enum Issue {
+    #[command(name = "close")]
+    Close(IssueClose),
+    #[command(name = "reopen")]
+    Reopen(IssueReopen),
+    #[command(name = "label")]
+    #[subcommands(Add, Remove)]
+    Label(IssueLabel),
+    #[command(name = "assign")]
+    Assign(IssueAssign),
+    #[command(name = "unassign")]
+    Unassign(IssueUnassign),
+    #[command(name = "comment")]
+    Comment(IssueComment),
+    #[command(name = "lock")]
+    Lock(IssueLock),
+    #[command(name = "search")]
+    Search(IssueSearch),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "close")]
+/// Close an issue
+pub struct IssueClose {
+    /// Repository of the issue
+    #[command(autocomplete = true)]
+    repo: String,
+    /// Number of the issue
+    #[command(min_value = 1)]
+    number: i64,
+    /// Reason for closing the issue
+    reason: Option<CloseReason>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum CloseReason {
+    #[option(name = "Completed", value = "completed")]
+    Completed,
+    #[option(name = "Not planned", value = "not_planned")]
+    NotPlanned,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reopen")]
+/// Reopen a closed issue
+pub struct IssueReopen {
+    /// Repository of the issue
+    #[command(autocomplete = true)]
+    repo: String,
+    /// Number of the issue
+    #[command(min_value = 1)]
+    number: i64,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "label")]
+/// Add or remove labels of an issue
+pub enum IssueLabel {
+    #[command(name = "add")]
+    Add(IssueLabelAdd),
+    #[command(name = "remove")]
+    Remove(IssueLabelRemove),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "add")]
+/// Add labels to an issue
+pub struct IssueLabelAdd {
+    /// Repository of the issue
+    #[command(autocomplete = true)]
+    repo: String,
+    /// Number of the issue
+    #[command(min_value = 1)]
+    number: i64,
+    /// Labels to add, separated by commas
+    labels: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove")]
+/// Remove a label from an issue
+pub struct IssueLabelRemove {
+    /// Repository of the issue
+    #[command(autocomplete = true)]
+    repo: String,
+    /// Number of the issue
+    #[command(min_value = 1)]
+    number: i64,
+    /// Label to remove
+    #[command(autocomplete = true)]
+    label: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "assign")]
+/// Assign github users to an issue
+pub struct IssueAssign {
+    /// Repository of the issue
+    #[command(autocomplete = true)]
+    repo: String,
+    /// Number of the issue
+    #[command(min_value = 1)]
+    number: i64,
+    /// Github usernames, separated by commas
+    users: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "unassign")]
+/// Unassign github users from an issue
+pub struct IssueUnassign {
+    /// Repository of the issue
+    #[command(autocomplete = true)]
+    repo: String,
+    /// Number of the issue
+    #[command(min_value = 1)]
+    number: i64,
+    /// Github usernames, separated by commas
+    users: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "comment")]
+/// Comment on an issue
+pub struct IssueComment {
+    /// Repository of the issue
+    #[command(autocomplete = true)]
+    repo: String,
+    /// Number of the issue
+    #[command(min_value = 1)]
+    number: i64,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "lock")]
+/// Lock the conversation of an issue
+pub struct IssueLock {
+    /// Repository of the issue
+    #[command(autocomplete = true)]
+    repo: String,
+    /// Number of the issue
+    #[command(min_value = 1)]
+    number: i64,
+    /// Reason for locking the issue
+    reason: Option<LockReason>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum LockReason {
+    #[option(name = "Off-topic", value = "off_topic")]
+    OffTopic,
+    #[option(name = "Too heated", value = "too_heated")]
+    TooHeated,
+    #[option(name = "Resolved", value = "resolved")]
+    Resolved,
+    #[option(name = "Spam", value = "spam")]
+    Spam,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "search")]
+/// Search for github issues
+pub struct IssueSearch {
+    /// Text to search for
+    #[command(max_length = 256)]
+    query: String,
+    /// Only search in this repository
+    #[command(autocomplete = true)]
+    repo: Option<String>,
+    /// Only search for issues with this state
+    state: Option<IssueSearchState>,
+    /// Only search for issues with this label
+    #[command(autocomplete = true)]
+    label: Option<String>,
+    /// Only search for issues of this github user
+    author: Option<String>,
+}
+
+#[derive(CommandOption, CreateOption)]
+pub enum IssueSearchState {
+    #[option(name = "Open", value = "open")]
+    Open,
+    #[option(name = "Closed", value = "closed")]
+    Closed,
+}
+
+impl From<LockReason> for GithubLockReason {
+    fn from(reason: LockReason) -> Self {
+        match reason {
+            LockReason::OffTopic => Self::OffTopic,
+            LockReason::TooHeated => Self::TooHeated,
+            LockReason::Resolved => Self::Resolved,
+            LockReason::Spam => Self::Spam,
+        }
+    }
+}
+
+async fn slash_issue_close(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: IssueClose,
+) -> Result<()> {
+    let reason = match args.reason {
+        Some(CloseReason::NotPlanned) => IssueStateReason::NotPlanned,
+        Some(CloseReason::Completed) | None => IssueStateReason::Completed,
+    };
+
+    let action = IssueAction::Close(reason);
+
+    apply_action(ctx, command, &args.repo, args.number, action).await
+}
+
+async fn slash_issue_reopen(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: IssueReopen,
+) -> Result<()> {
+    apply_action(ctx, command, &args.repo, args.number, IssueAction::Reopen).await
+}
+
+async fn slash_issue_label_add(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: IssueLabelAdd,
+) -> Result<()> {
+    let action = IssueAction::AddLabels(split_list(&args.labels));
+
+    apply_action(ctx, command, &args.repo, args.number, action).await
+}
+
+async fn slash_issue_label_remove(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: IssueLabelRemove,
+) -> Result<()> {
+    let action = IssueAction::RemoveLabel(args.label.trim().to_owned());
+
+    apply_action(ctx, command, &args.repo, args.number, action).await
+}
+
+async fn slash_issue_assign(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: IssueAssign,
+) -> Result<()> {
+    let action = IssueAction::Assign(split_list(&args.users));
+
+    apply_action(ctx, command, &args.repo, args.number, action).await
+}
+
+async fn slash_issue_unassign(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: IssueUnassign,
+) -> Result<()> {
+    let action = IssueAction::Unassign(split_list(&args.users));
+
+    apply_action(ctx, command, &args.repo, args.number, action).await
+}
+
+async fn slash_issue_lock(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: IssueLock,
+) -> Result<()> {
+    let action = IssueAction::Lock(args.reason.map(GithubLockReason::from));
+
+    apply_action(ctx, command, &args.repo, args.number, action).await
+}
+
+async fn apply_action(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    repo: &str,
+    number: i64,
+    action: IssueAction,
+) -> Result<()> {
+    let project = ctx
+        .project(command.guild_id)
+        .wrap_err("Missing project of guild")?;
+
+    let Some(issue) = IssueRef::resolve(&project, repo, number as u64) else {
+        let content = t!(&command.locale, "issue-unknown-repository", repo = repo);
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    };
+
+    if action.is_destructive() {
+        let confirm = ConfirmAction::new(issue, action, &command.locale);
+
+        return ActiveMessages::builder(confirm)
+            .start_by_update(true)
+            .begin(ctx, command)
+            .await
+            .wrap_err("Failed to begin active message");
+    }
+
+    let embed = match issue.apply(&ctx, &action, &command.locale).await {
+        Ok(embed) => embed,
+        Err(err) => {
+            let content = t!(
+                &command.locale,
+                "issue-update-failed",
+                issue = issue.to_string()
+            );
+            let _ = command.error(&ctx, content).await;
+
+            return Err(err);
+        }
+    };
+
+    let operator = command.user().ok().map(Operator::from);
+    let entry = AuditEntry::new(command.guild_id, operator, action.audit(&issue));
+    audit::record(&ctx, entry).await;
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(&ctx, &builder).await?;
+
+    Ok(())
+}
+
+async fn slash_issue_comment(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: IssueComment,
+) -> Result<()> {
+    let project = ctx
+        .project(command.guild_id)
+        .wrap_err("Missing project of guild")?;
+
+    let Some(issue) = IssueRef::resolve(&project, &args.repo, args.number as u64) else {
+        let content = t!(
+            &command.locale,
+            "issue-unknown-repository",
+            repo = args.repo.as_str()
+        );
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    };
+
+    let author = command.username()?.to_owned();
+
+    ActiveMessages::builder(IssueCommentActive::new(issue, author, &command.locale))
+        .start_by_update(true)
+        .begin(ctx, command)
+        .await
+        .wrap_err("Failed to begin active message")
+}
+
+async fn slash_issue_search(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    args: IssueSearch,
+) -> Result<()> {
+    const PER_PAGE: usize = 10;
+
+    let project = ctx
+        .project(command.guild_id)
+        .wrap_err("Missing project of guild")?;
+
+    let github = &project.github_config;
+
+    let mut query = format!("{} is:issue", args.query);
+
+    match args.repo {
+        Some(ref repo) => match github.repository(repo) {
+            Some(repo) => {
+                let _ = write!(query, " repo:{}/{repo}", github.owner);
+            }
+            None => {
+                let content = t!(
+                    &command.locale,
+                    "issue-unknown-repository",
+                    repo = repo.as_str()
+                );
+                command.error(&ctx, content).await?;
+
+                return Ok(());
+            }
+        },
+        None => {
+            for repo in github.repositories.values() {
+                let _ = write!(query, " repo:{}/{repo}", github.owner);
+            }
+        }
+    }
+
+    match args.state {
+        Some(IssueSearchState::Open) => query.push_str(" state:open"),
+        Some(IssueSearchState::Closed) => query.push_str(" state:closed"),
+        None => {}
+    }
+
+    let qualifiers = [("label", &args.label), ("author", &args.author)];
+
+    for (qualifier, value) in qualifiers {
+        let Some(value) = value else { continue };
+
+        match search_value(value) {
+            Some(value) => {
+                let _ = write!(query, " {qualifier}:{value}");
+            }
+            None => {
+                let content = t!(
+                    &command.locale,
+                    "search-invalid-value",
+                    value = value.as_str()
+                );
+                command.error(&ctx, content).await?;
+
+                return Ok(());
+            }
+        }
+    }
+
+    let (search, total) = match IssueSearchPagination::new(&ctx, query, PER_PAGE).await {
+        Ok(tuple) => tuple,
+        Err(err) => {
+            let _ = command
+                .error(&ctx, t!(&command.locale, "search-failed"))
+                .await;
+
+            return Err(err);
+        }
+    };
+
+    if total == 0 {
+        command
+            .error(&ctx, t!(&command.locale, "search-empty"))
+            .await?;
+
+        return Ok(());
+    }
+
+    ActiveMessages::builder(Pagination::new(search, total, PER_PAGE, &command.locale))
+        .start_by_update(true)
+        .begin(ctx, command)
+        .await
+        .wrap_err("Failed to begin active message")
+}
+
+async fn autocomplete_issue(
+    ctx: Arc<Context>,
+    input: AutocompleteInput,
+) -> Result<Vec<CommandOptionChoice>> {
+    let Some(project) = ctx.project(input.guild_id) else {
+        return Ok(Vec::new());
+    };
+
+    let github = &project.github_config;
+
+    let candidates: Vec<String> = match input.name.as_str() {
+        "repo" => github.repositories.keys().cloned().collect(),
+        "label" => {
+            let repos: Vec<&str> = match input
+                .option_str("repo")
+                .and_then(|repo| github.repository(repo))
+            {
+                Some(repo) => vec![repo],
+                None => github.repositories.values().map(String::as_str).collect(),
+            };
+
+            let mut labels = BTreeSet::new();
+
+            for repo in repos {
+                let key = (github.owner.clone(), repo.to_owned());
+
+                let fetch = || async {
+                    repo_labels(&ctx.github, &github.owner, repo)
+                        .await
+                        .map(Arc::from)
+                        .wrap_err_with(|| format!("Failed to request labels of {repo}"))
+                };
+
+                let cached = ctx.label_cache.get_or_fetch(key, fetch).await?;
+                labels.extend(cached.iter().cloned());
+            }
+
+            labels.into_iter().collect()
+        }
+        _ => Vec::new(),
+    };
+
+    let choices = candidates
+        .into_iter()
+        .filter(|candidate| input.matches(candidate))
+        .map(|candidate| CommandOptionChoice {
+            name: candidate.clone(),
+            name_localizations: None,
+            value: CommandOptionChoiceValue::String(candidate),
+        })
+        .collect();
+
+    Ok(choices)
+}
+
+fn split_list(list: &str) -> Vec<String> {
+    list.split(',')
+        .map(str::trim)
+        .filter(|item| !item.is_empty())
+        .map(str::to_owned)
+        .collect()
+}
@@ -1,3 +1,7 @@
-pub use self::create_issue::*;
+pub use self::{accept_post::*, create_issue::*, issue::*, report::*, user_reports::*};
 
+mod accept_post;
 mod create_issue;
+mod issue;
+mod report;
+mod user_reports;
@@ -0,0 +1,105 @@
+use std::sync::Arc;
+
+use command_macros::SlashCommand;
+use eyre::{ContextCompat, Result, WrapErr};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    active::{ActiveMessages, ReportReview, ReportSubmission, REPORT_MODAL},
+    core::{commands::Cooldown, reports::PendingReport, Context},
+    util::{
+        builder::MessageBuilder,
+        ext::{Authored, InteractionCommandExt, ModalExt},
+        interaction::{InteractionCommand, InteractionModal},
+        locale::t,
+    },
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "report")]
+#[flags(ONLY_GUILDS, SKIP_DEFER)]
+/// Report a bug or suggest a feature to the staff
+pub struct Report;
+
+/// Charged once a report is submitted rather than when its modal is opened. Named apart from
+/// the command so that a configured cooldown of `report` doesn't charge twice.
+const REPORT_COOLDOWN: Cooldown = Cooldown::new(3, 3600);
+
+async fn slash_report(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    let reports_enabled = ctx
+        .project(command.guild_id)
+        .is_some_and(|project| project.reports.is_some());
+
+    if !reports_enabled {
+        command
+            .error_callback(&ctx, t!(&command.locale, "reports-disabled"))
+            .await?;
+
+        return Ok(());
+    }
+
+    let title = t!(&command.locale, "reports-submit-modal");
+    let modal = ReportSubmission::modal(REPORT_MODAL, &title, None, &command.locale);
+
+    command
+        .modal(&ctx, modal)
+        .await
+        .wrap_err("Failed to open report modal")?;
+
+    Ok(())
+}
+
+/// Post a report that was submitted through the `/report` modal into the queue channel
+pub async fn handle_report_modal(ctx: Arc<Context>, mut modal: InteractionModal) -> Result<()> {
+    let cooldown = ctx.check_cooldown(
+        "report_submit",
+        modal.user_id()?,
+        Some(REPORT_COOLDOWN),
+        &modal.locale,
+    );
+
+    if let Some(content) = cooldown {
+        modal.error_callback(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let guild = modal.guild_id.wrap_err("Missing guild of report modal")?;
+    let project = ctx
+        .project(Some(guild))
+        .wrap_err("Missing project of guild")?;
+
+    let queue = project
+        .reports
+        .as_ref()
+        .wrap_err("Missing reports config")?
+        .queue_channel_as_marker();
+
+    let report = ReportSubmission::from_modal(&mut modal)?;
+    let review = ReportReview::new(report.clone(), project, Arc::clone(&ctx.reports));
+
+    let message = ActiveMessages::builder(review)
+        .begin_in_channel(Arc::clone(&ctx), queue, guild)
+        .await?;
+
+    let pending = PendingReport {
+        message,
+        channel: queue,
+        guild,
+        report,
+    };
+
+    if let Err(err) = ctx.reports.insert(pending).await {
+        warn!(?err, "Failed to store pending report");
+    }
+
+    let content = t!(&modal.locale, "reports-sent");
+    let builder = MessageBuilder::new().content(content);
+
+    modal
+        .reply(&ctx, builder, true)
+        .await
+        .wrap_err("Failed to reply to report modal")?;
+
+    Ok(())
+}
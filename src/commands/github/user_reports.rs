@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use command_macros::UserCommand;
+use eyre::{ContextCompat, Result, WrapErr};
+use twilight_model::id::marker::UserMarker;
+
+use crate::{
+    active::{ActiveMessages, Pagination, UserReportsPagination},
+    core::Context,
+    util::{ext::InteractionCommandExt, interaction::InteractionCommand, locale::t},
+};
+
+#[derive(UserCommand)]
+#[command(name = "Show this user's reports", dm_permission = false)]
+#[flags(AUTHORITY, ONLY_GUILDS)]
+pub struct UserReports;
+
+async fn user_userreports(ctx: Arc<Context>, command: InteractionCommand) -> Result<()> {
+    const PER_PAGE: usize = 10;
+
+    let user = command
+        .data
+        .target_id
+        .wrap_err("Missing target of command")?
+        .cast::<UserMarker>();
+
+    let name = command
+        .data
+        .resolved
+        .as_ref()
+        .and_then(|resolved| resolved.users.get(&user))
+        .map(|user| user.name.clone());
+
+    let project = ctx
+        .project(command.guild_id)
+        .wrap_err("Missing project of guild")?;
+
+    // Searching github may take longer than discord waits for a response
+    command
+        .defer(&ctx, false)
+        .await
+        .wrap_err("Failed to defer")?;
+
+    let reports = match UserReportsPagination::new(&ctx, &project, user, name.as_deref()).await {
+        Ok(reports) => reports,
+        Err(err) => {
+            let _ = command
+                .error(&ctx, t!(&command.locale, "user-reports-failed"))
+                .await;
+
+            return Err(err);
+        }
+    };
+
+    if reports.is_empty() {
+        let content = t!(
+            &command.locale,
+            "user-reports-empty",
+            user = user.to_string()
+        );
+        command.error(&ctx, content).await?;
+
+        return Ok(());
+    }
+
+    let amount = reports.len();
+
+    ActiveMessages::builder(Pagination::new(reports, amount, PER_PAGE, &command.locale))
+        .start_by_update(true)
+        .begin(ctx, command)
+        .await
+        .wrap_err("Failed to begin active message")
+}
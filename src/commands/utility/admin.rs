@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use command_macros::SlashCommand;
+use eyre::{Result, WrapErr};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::{reload_config, Context},
+    util::{
+        builder::{EmbedBuilder, MessageBuilder},
+        ext::InteractionCommandExt,
+        interaction::InteractionCommand,
+        locale::t,
+    },
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "admin")]
+#[flags(ONLY_OWNER, EPHEMERAL)]
+/// Manage the bot
+pub enum Admin {
+    #[command(name = "reload")]
+    Reload(AdminReload),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reload")]
+/// Reload the configuration file
+pub struct AdminReload;
+
+async fn slash_admin_reload(
+    ctx: Arc<Context>,
+    command: InteractionCommand,
+    _: AdminReload,
+) -> Result<()> {
+    let config = match reload_config(&ctx).await {
+        Ok(config) => config,
+        Err(err) => {
+            let content = t!(
+                &command.locale,
+                "config-reload-failed",
+                error = format!("{err:#}")
+            );
+            command.error(&ctx, content).await?;
+
+            return Err(err);
+        }
+    };
+
+    let projects: Vec<_> = config
+        .projects
+        .iter()
+        .map(|project| format!("- {}", project.title))
+        .collect();
+
+    let embed = EmbedBuilder::new()
+        .title(t!(&command.locale, "config-reloaded"))
+        .description(projects.join("\n"));
+
+    command
+        .update(&ctx, &MessageBuilder::new().embed(embed))
+        .await
+        .wrap_err("Failed to update")?;
+
+    Ok(())
+}
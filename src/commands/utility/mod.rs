@@ -1,3 +1,4 @@
-pub use self::ping::*;
+pub use self::{admin::*, ping::*};
 
+mod admin;
 mod ping;
@@ -5,7 +5,10 @@ use eyre::{ContextCompat, Result, WrapErr};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 
 use crate::{
-    core::Context,
+    core::{
+        metrics::{Api, ObserveExt},
+        Context,
+    },
     util::{
         builder::MessageBuilder,
         ext::{InteractionCommandExt, MessageExt},
@@ -15,7 +18,7 @@ use crate::{
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "ping")]
-#[flags(SKIP_DEFER)]
+#[flags(AUTHORITY, SKIP_DEFER)]
 /// Check if the bot is online
 pub struct Ping;
 
@@ -31,6 +34,7 @@ async fn slash_ping(ctx: Arc<Context>, command: InteractionCommand) -> Result<()
     let response_raw = ctx
         .interaction()
         .response(&command.token)
+        .observe(Api::Discord, "response")
         .await
         .wrap_err("Failed to receive response")?;
 
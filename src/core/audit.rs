@@ -0,0 +1,365 @@
+use std::path::Path;
+
+use eyre::{Result, WrapErr};
+use serde::Serialize;
+use time::OffsetDateTime;
+use tokio::{
+    fs::{self, OpenOptions},
+    io::AsyncWriteExt,
+    sync::Mutex,
+};
+use twilight_model::{
+    channel::message::embed::EmbedField,
+    id::{
+        marker::{GuildMarker, UserMarker},
+        Id,
+    },
+    user::User,
+};
+
+use crate::{
+    core::Context,
+    util::{
+        builder::{EmbedBuilder, MessageBuilder},
+        constants::RED,
+        ext::ChannelExt,
+    },
+};
+
+/// Serializes appends to the audit file
+#[derive(Default)]
+pub struct AuditLog {
+    lock: Mutex<()>,
+}
+
+impl AuditLog {
+    async fn append(&self, path: &Path, entry: &AuditEntry) -> Result<()> {
+        let mut line = serde_json::to_vec(entry).wrap_err("Failed to serialize audit entry")?;
+        line.push(b'\n');
+
+        let _guard = self.lock.lock().await;
+
+        if let Some(parent) = path
+            .parent()
+            .filter(|parent| !parent.as_os_str().is_empty())
+        {
+            fs::create_dir_all(parent)
+                .await
+                .wrap_err_with(|| format!("Failed to create directory {parent:?}"))?;
+        }
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .await
+            .wrap_err_with(|| format!("Failed to open audit file at {path:?}"))?;
+
+        file.write_all(&line)
+            .await
+            .wrap_err("Failed to write audit entry")
+    }
+}
+
+/// Append the entry to the audit file and post it into the audit channel of the guild's project.
+///
+/// Failures are only logged so that auditing never interrupts the action itself.
+pub async fn record(ctx: &Context, entry: AuditEntry) {
+    info!(action = entry.action.name(), operator = ?entry.operator, "Audit");
+
+    let config = ctx.config();
+
+    if let Some(ref path) = config.audit_path {
+        if let Err(err) = ctx.audit.append(path, &entry).await {
+            warn!(?err, "Failed to append audit entry");
+        }
+    }
+
+    let channel = entry
+        .guild
+        .and_then(|guild| config.project(guild))
+        .and_then(|project| project.discord_config.audit_channel_as_marker());
+
+    let Some(channel) = channel else { return };
+
+    let builder = MessageBuilder::new().embed(entry.embed());
+
+    if let Err(err) = channel.create_message(ctx, &builder, None).await {
+        warn!(?err, "Failed to post audit entry");
+    }
+}
+
+#[derive(Serialize)]
+pub struct AuditEntry {
+    #[serde(with = "time::serde::rfc3339")]
+    pub timestamp: OffsetDateTime,
+    pub guild: Option<Id<GuildMarker>>,
+    /// User that performed the action; `None` if it was triggered automatically
+    pub operator: Option<Operator>,
+    #[serde(flatten)]
+    pub action: AuditAction,
+}
+
+impl AuditEntry {
+    pub fn new(
+        guild: Option<Id<GuildMarker>>,
+        operator: Option<Operator>,
+        action: AuditAction,
+    ) -> Self {
+        Self {
+            timestamp: OffsetDateTime::now_utc(),
+            guild,
+            operator,
+            action,
+        }
+    }
+
+    fn embed(&self) -> EmbedBuilder {
+        let mut fields = Vec::new();
+
+        if let Some(ref operator) = self.operator {
+            fields.push(field(
+                "Operator",
+                format!("<@{}> (`{}`)", operator.id, operator.name),
+            ));
+        }
+
+        let embed = match self.action {
+            AuditAction::IssueCreated {
+                ref repo,
+                ref issue,
+                ref title,
+                ref labels,
+                ref source,
+            } => {
+                if let Some(source) = source {
+                    fields.push(field("Source", format!("[Jump]({source})")));
+                }
+
+                fields.push(field("Repository", repo.clone()));
+
+                if !labels.is_empty() {
+                    fields.push(field("Labels", labels.join(", ")));
+                }
+
+                EmbedBuilder::new()
+                    .title("Issue created")
+                    .url(issue.clone())
+                    .description(title.clone())
+            }
+            AuditAction::CommentAdded {
+                ref issue,
+                ref comment,
+            } => EmbedBuilder::new()
+                .title("Comment added")
+                .url(comment.clone())
+                .description(format!("On [{issue}]({issue})")),
+            AuditAction::IssueClosed {
+                ref issue,
+                ref reason,
+            } => EmbedBuilder::new()
+                .title("Issue closed")
+                .url(issue.clone())
+                .description(format!("{issue}\nReason: {reason}")),
+            AuditAction::IssueReopened { ref issue } => EmbedBuilder::new()
+                .title("Issue reopened")
+                .url(issue.clone())
+                .description(issue.clone()),
+            AuditAction::LabelsAdded {
+                ref issue,
+                ref labels,
+            } => EmbedBuilder::new()
+                .title("Labels added")
+                .url(issue.clone())
+                .description(format!("{issue}\nLabels: {}", labels.join(", "))),
+            AuditAction::LabelRemoved {
+                ref issue,
+                ref label,
+            } => EmbedBuilder::new()
+                .title("Label removed")
+                .url(issue.clone())
+                .description(format!("{issue}\nLabel: {label}")),
+            AuditAction::AssigneesAdded {
+                ref issue,
+                ref assignees,
+            } => EmbedBuilder::new()
+                .title("Assignees added")
+                .url(issue.clone())
+                .description(format!("{issue}\nAssignees: {}", assignees.join(", "))),
+            AuditAction::AssigneesRemoved {
+                ref issue,
+                ref assignees,
+            } => EmbedBuilder::new()
+                .title("Assignees removed")
+                .url(issue.clone())
+                .description(format!("{issue}\nAssignees: {}", assignees.join(", "))),
+            AuditAction::IssueLocked {
+                ref issue,
+                ref reason,
+            } => {
+                let description = match reason {
+                    Some(reason) => format!("{issue}\nReason: {reason}"),
+                    None => issue.clone(),
+                };
+
+                EmbedBuilder::new()
+                    .title("Issue locked")
+                    .url(issue.clone())
+                    .description(description)
+            }
+            AuditAction::ReportRejected {
+                reporter,
+                ref title,
+                ref reason,
+            } => {
+                fields.push(field("Reporter", format!("<@{reporter}>")));
+
+                EmbedBuilder::new()
+                    .title("Report rejected")
+                    .description(format!("{title}\nReason: {reason}"))
+            }
+            AuditAction::ReportMarkedDuplicate {
+                reporter,
+                ref title,
+                ref duplicate_of,
+            } => {
+                fields.push(field("Reporter", format!("<@{reporter}>")));
+
+                EmbedBuilder::new()
+                    .title("Report marked as duplicate")
+                    .url(duplicate_of.clone())
+                    .description(format!("{title}\nDuplicate of {duplicate_of}"))
+            }
+            AuditAction::DraftAbandoned { ref draft } => EmbedBuilder::new()
+                .title("Draft abandoned")
+                .description(draft.clone()),
+            AuditAction::DraftExpired { ref draft } => EmbedBuilder::new()
+                .title("Draft expired")
+                .description(draft.clone()),
+            AuditAction::PermissionRejected { ref attempted } => EmbedBuilder::new()
+                .title("Permission rejected")
+                .color(RED)
+                .description(format!("Attempted to use `{attempted}`")),
+        };
+
+        embed.fields(fields).timestamp(self.timestamp)
+    }
+}
+
+fn field(name: &str, value: String) -> EmbedField {
+    EmbedField {
+        inline: true,
+        name: name.to_owned(),
+        value,
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Operator {
+    pub id: Id<UserMarker>,
+    pub name: String,
+}
+
+impl From<&User> for Operator {
+    fn from(user: &User) -> Self {
+        Self {
+            id: user.id,
+            name: user.name.clone(),
+        }
+    }
+}
+
+/// State-changing actions of the bot
+#[derive(Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum AuditAction {
+    IssueCreated {
+        repo: String,
+        /// Url of the issue
+        issue: String,
+        title: String,
+        labels: Vec<String>,
+        /// Jump link to the discord message or post the issue was created from
+        source: Option<String>,
+    },
+    CommentAdded {
+        /// Url of the issue
+        issue: String,
+        /// Url of the comment
+        comment: String,
+    },
+    IssueClosed {
+        /// Url of the issue
+        issue: String,
+        reason: String,
+    },
+    IssueReopened {
+        /// Url of the issue
+        issue: String,
+    },
+    LabelsAdded {
+        /// Url of the issue
+        issue: String,
+        labels: Vec<String>,
+    },
+    LabelRemoved {
+        /// Url of the issue
+        issue: String,
+        label: String,
+    },
+    AssigneesAdded {
+        /// Url of the issue
+        issue: String,
+        assignees: Vec<String>,
+    },
+    AssigneesRemoved {
+        /// Url of the issue
+        issue: String,
+        assignees: Vec<String>,
+    },
+    IssueLocked {
+        /// Url of the issue
+        issue: String,
+        reason: Option<String>,
+    },
+    /// A report of the queue channel was rejected
+    ReportRejected {
+        reporter: Id<UserMarker>,
+        title: String,
+        reason: String,
+    },
+    /// A report of the queue channel was marked as duplicate of an existing issue
+    ReportMarkedDuplicate {
+        reporter: Id<UserMarker>,
+        title: String,
+        /// Url of the existing issue
+        duplicate_of: String,
+    },
+    /// An operator cancelled the draft
+    DraftAbandoned { draft: String },
+    /// The draft timed out before it was finished
+    DraftExpired { draft: String },
+    /// A user without the required permission tried to use a command or component
+    PermissionRejected { attempted: String },
+}
+
+impl AuditAction {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::IssueCreated { .. } => "issue_created",
+            Self::CommentAdded { .. } => "comment_added",
+            Self::IssueClosed { .. } => "issue_closed",
+            Self::IssueReopened { .. } => "issue_reopened",
+            Self::LabelsAdded { .. } => "labels_added",
+            Self::LabelRemoved { .. } => "label_removed",
+            Self::AssigneesAdded { .. } => "assignees_added",
+            Self::AssigneesRemoved { .. } => "assignees_removed",
+            Self::IssueLocked { .. } => "issue_locked",
+            Self::ReportRejected { .. } => "report_rejected",
+            Self::ReportMarkedDuplicate { .. } => "report_marked_duplicate",
+            Self::DraftAbandoned { .. } => "draft_abandoned",
+            Self::DraftExpired { .. } => "draft_expired",
+            Self::PermissionRejected { .. } => "permission_rejected",
+        }
+    }
+}
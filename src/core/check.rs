@@ -0,0 +1,217 @@
+use std::{collections::BTreeSet, path::Path};
+
+use eyre::Result;
+use octocrab::{Octocrab, OctocrabBuilder};
+use twilight_http::Client;
+
+use crate::util::{
+    config::{Config, ConfigFormat, Project},
+    github::repo_labels,
+};
+
+/// Scopes of classic tokens that allow creating and editing issues
+const REQUIRED_SCOPES: [&str; 2] = ["repo", "public_repo"];
+
+/// Verify the config file and everything it refers to.
+///
+/// Prints a report and fails if any problem was found.
+pub async fn check_config(path: &Path, format: ConfigFormat) -> Result<()> {
+    let mut report = Report::default();
+
+    let config = match Config::load(path, format) {
+        Ok(config) => {
+            report.ok(format!("Parsed {format} config at {path:?}"));
+
+            config
+        }
+        Err(err) => {
+            report.problem(format!("{err:#}"));
+
+            return report.finish();
+        }
+    };
+
+    check_discord(&config, &mut report).await;
+
+    match OctocrabBuilder::new()
+        .personal_token(config.github_token.expose().to_owned())
+        .build()
+    {
+        Ok(github) => {
+            check_github_token(&github, &mut report).await;
+
+            for project in config.projects.iter() {
+                check_repositories(&github, project, &mut report).await;
+            }
+        }
+        Err(err) => report.problem(format!("Failed to build github client: {err}")),
+    }
+
+    report.finish()
+}
+
+async fn check_discord(config: &Config, report: &mut Report) {
+    let http = Client::new(config.discord_token.expose().to_owned());
+
+    let user = match http.current_user().await {
+        Ok(response) => response.model().await.ok(),
+        Err(err) => {
+            report.problem(format!("Discord token was rejected: {err}"));
+
+            return;
+        }
+    };
+
+    match user {
+        Some(user) => report.ok(format!("Discord token belongs to `{}`", user.name)),
+        None => report.problem("Failed to deserialize discord user"),
+    }
+
+    for project in config.projects.iter() {
+        for guild in project.discord_config.guilds_as_marker() {
+            match http.guild(guild).await {
+                Ok(_) => report.ok(format!("[{}] Access to guild {guild}", project.title)),
+                Err(err) => report.problem(format!(
+                    "[{}] No access to guild {guild}: {err}",
+                    project.title
+                )),
+            }
+        }
+    }
+}
+
+async fn check_github_token(github: &Octocrab, report: &mut Report) {
+    let response = match github._get("https://api.github.com/user").await {
+        Ok(response) if response.status().is_success() => response,
+        Ok(response) => {
+            report.problem(format!(
+                "Github token was rejected with status {}",
+                response.status()
+            ));
+
+            return;
+        }
+        Err(err) => {
+            report.problem(format!("Failed to request github user: {err}"));
+
+            return;
+        }
+    };
+
+    // Only classic tokens report their scopes
+    let Some(scopes) = response
+        .headers()
+        .get("x-oauth-scopes")
+        .and_then(|scopes| scopes.to_str().ok())
+    else {
+        report.ok("Github token is valid; its permissions are checked per repository");
+
+        return;
+    };
+
+    let has_scope = scopes
+        .split(',')
+        .map(str::trim)
+        .any(|scope| REQUIRED_SCOPES.contains(&scope));
+
+    if has_scope {
+        report.ok(format!("Github token has scopes `{scopes}`"));
+    } else {
+        report.problem(format!(
+            "Github token has scopes `{scopes}` but requires `repo` or `public_repo`"
+        ));
+    }
+}
+
+async fn check_repositories(github: &Octocrab, project: &Project, report: &mut Report) {
+    let owner = &project.github_config.owner;
+    let title = &project.title;
+
+    let mut labels: BTreeSet<&str> = project
+        .issue_labels
+        .issue_types
+        .iter()
+        .chain(project.issue_labels.issue_priority.iter())
+        .map(String::as_str)
+        .collect();
+
+    if let Some(ref forum) = project.forum {
+        labels.extend(forum.tag_labels.values().map(String::as_str));
+    }
+
+    for repo in project.github_config.repositories.values() {
+        match github.repos(owner, repo).get().await {
+            Ok(repository) => {
+                let writable = repository
+                    .permissions
+                    .is_some_and(|permissions| permissions.push);
+
+                if writable {
+                    report.ok(format!("[{title}] Repository {owner}/{repo} is writable"));
+                } else {
+                    report.problem(format!(
+                        "[{title}] Repository {owner}/{repo} is not writable"
+                    ));
+                }
+            }
+            Err(err) => {
+                report.problem(format!(
+                    "[{title}] Repository {owner}/{repo} not found: {err}"
+                ));
+
+                continue;
+            }
+        }
+
+        let existing = match repo_labels(github, owner, repo).await {
+            Ok(existing) => existing,
+            Err(err) => {
+                report.problem(format!(
+                    "[{title}] Failed to request labels of {owner}/{repo}: {err}"
+                ));
+
+                continue;
+            }
+        };
+
+        // Github label names are case-insensitive
+        for label in labels.iter() {
+            if !existing
+                .iter()
+                .any(|existing| existing.eq_ignore_ascii_case(label))
+            {
+                report.problem(format!(
+                    "[{title}] Label `{label}` does not exist in {owner}/{repo}"
+                ));
+            }
+        }
+    }
+}
+
+#[derive(Default)]
+struct Report {
+    problems: usize,
+}
+
+impl Report {
+    fn ok(&self, msg: impl AsRef<str>) {
+        println!("✓ {}", msg.as_ref());
+    }
+
+    fn problem(&mut self, msg: impl AsRef<str>) {
+        self.problems += 1;
+        println!("✗ {}", msg.as_ref());
+    }
+
+    fn finish(self) -> Result<()> {
+        match self.problems {
+            0 => {
+                println!("\nNo problems found");
+
+                Ok(())
+            }
+            1 => bail!("Found 1 problem"),
+            n => bail!("Found {n} problems"),
+        }
+    }
+}
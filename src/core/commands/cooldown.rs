@@ -0,0 +1,105 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use twilight_model::id::{marker::UserMarker, Id};
+
+/// Allow `uses` per user within `per` seconds
+#[derive(Copy, Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Cooldown {
+    pub uses: u32,
+    pub per: u64,
+}
+
+impl Cooldown {
+    pub const fn new(uses: u32, per: u64) -> Self {
+        Self { uses, per }
+    }
+}
+
+/// Time after which buckets whose uses all expired are dropped
+const PRUNE_INTERVAL: Duration = Duration::from_secs(600);
+
+/// Recent uses of commands and active message actions, bucketed per user
+#[derive(Default)]
+pub struct Cooldowns {
+    inner: Mutex<CooldownsInner>,
+}
+
+#[derive(Default)]
+struct CooldownsInner {
+    buckets: HashMap<(String, Id<UserMarker>), Bucket>,
+    last_prune: Option<Instant>,
+}
+
+struct Bucket {
+    period: Duration,
+    uses: VecDeque<Instant>,
+}
+
+impl Bucket {
+    /// Drop the uses that are older than the period
+    fn prune(&mut self, now: Instant) {
+        while self
+            .uses
+            .front()
+            .is_some_and(|used| now - *used >= self.period)
+        {
+            self.uses.pop_front();
+        }
+    }
+}
+
+impl Cooldowns {
+    /// Register a use of the bucket `key` by the user.
+    ///
+    /// If the user exhausted the bucket, the use is not registered
+    /// and the remaining time until the next use is returned.
+    ///
+    /// Every [`PRUNE_INTERVAL`], buckets whose uses all expired are dropped along the way.
+    pub fn check(&self, key: &str, user: Id<UserMarker>, cooldown: Cooldown) -> Option<Duration> {
+        let now = Instant::now();
+        let period = Duration::from_secs(cooldown.per);
+
+        let mut inner = self.inner.lock().unwrap();
+
+        if inner
+            .last_prune
+            .map_or(true, |last| now - last >= PRUNE_INTERVAL)
+        {
+            inner.buckets.retain(|_, bucket| {
+                bucket.prune(now);
+
+                !bucket.uses.is_empty()
+            });
+
+            inner.last_prune = Some(now);
+        }
+
+        let bucket = inner
+            .buckets
+            .entry((key.to_owned(), user))
+            .or_insert_with(|| Bucket {
+                period,
+                uses: VecDeque::new(),
+            });
+
+        // The configured cooldown may have changed since the last use
+        bucket.period = period;
+        bucket.prune(now);
+
+        if bucket.uses.len() >= cooldown.uses as usize {
+            let oldest = bucket.uses.front().copied().unwrap_or(now);
+
+            return Some(period.saturating_sub(now - oldest));
+        }
+
+        bucket.uses.push_back(now);
+
+        None
+    }
+}
@@ -2,6 +2,9 @@ bitflags::bitflags! {
     pub struct CommandFlags: u8 {
         const EPHEMERAL   = 1 << 0;
         const SKIP_DEFER  = 1 << 1;
+        const AUTHORITY   = 1 << 2;
+        const ONLY_GUILDS = 1 << 3;
+        const ONLY_OWNER  = 1 << 4;
     }
 }
 
@@ -13,4 +16,16 @@ impl CommandFlags {
     pub fn ephemeral(self) -> bool {
         self.contains(CommandFlags::EPHEMERAL)
     }
+
+    pub fn authority(self) -> bool {
+        self.contains(CommandFlags::AUTHORITY)
+    }
+
+    pub fn only_guilds(self) -> bool {
+        self.contains(CommandFlags::ONLY_GUILDS)
+    }
+
+    pub fn only_owner(self) -> bool {
+        self.contains(CommandFlags::ONLY_OWNER)
+    }
 }
@@ -1,5 +1,9 @@
-pub use self::flags::CommandFlags;
+pub use self::{
+    cooldown::{Cooldown, Cooldowns},
+    flags::CommandFlags,
+};
 
+mod cooldown;
 mod flags;
 
 pub mod slash;
@@ -0,0 +1,110 @@
+use std::{future::Future, mem, sync::Arc};
+
+use eyre::{ContextCompat, Result, WrapErr};
+use twilight_model::{
+    application::{
+        command::CommandOptionChoice,
+        interaction::application_command::{CommandDataOption, CommandOptionValue},
+    },
+    id::{marker::GuildMarker, Id},
+};
+
+use crate::{
+    core::Context,
+    util::{ext::InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Discord shows at most this many choices
+const MAX_CHOICES: usize = 25;
+
+/// The option that the user is currently typing in
+pub struct AutocompleteInput {
+    pub guild_id: Option<Id<GuildMarker>>,
+    /// Names of the subcommand groups and subcommands leading to the option
+    pub subcommands: Vec<String>,
+    /// Name of the focused option
+    pub name: String,
+    /// What the user typed so far
+    pub value: String,
+    /// All options on the level of the focused one
+    pub options: Vec<CommandDataOption>,
+}
+
+impl AutocompleteInput {
+    fn from_command(command: &mut InteractionCommand) -> Option<Self> {
+        let mut subcommands = Vec::new();
+        let mut options = mem::take(&mut command.data.options);
+
+        loop {
+            let nested = options.iter().position(|option| {
+                matches!(
+                    option.value,
+                    CommandOptionValue::SubCommand(_) | CommandOptionValue::SubCommandGroup(_)
+                )
+            });
+
+            let Some(idx) = nested else { break };
+            let option = options.swap_remove(idx);
+            subcommands.push(option.name);
+
+            options = match option.value {
+                CommandOptionValue::SubCommand(options)
+                | CommandOptionValue::SubCommandGroup(options) => options,
+                _ => unreachable!(),
+            };
+        }
+
+        let (name, value) = options.iter().find_map(|option| match option.value {
+            CommandOptionValue::Focused(ref value, _) => Some((option.name.clone(), value.clone())),
+            _ => None,
+        })?;
+
+        Some(Self {
+            guild_id: command.guild_id,
+            subcommands,
+            name,
+            value,
+            options,
+        })
+    }
+
+    /// The string value of another option on the level of the focused one
+    pub fn option_str(&self, name: &str) -> Option<&str> {
+        self.options
+            .iter()
+            .find(|option| option.name == name)
+            .and_then(|option| match option.value {
+                CommandOptionValue::String(ref value) => Some(value.as_str()),
+                _ => None,
+            })
+    }
+
+    /// Whether the candidate contains what the user typed so far, ignoring case
+    pub fn matches(&self, candidate: &str) -> bool {
+        candidate
+            .to_lowercase()
+            .contains(&self.value.trim().to_lowercase())
+    }
+}
+
+/// Pass the focused option to the handler and respond with its choices
+pub async fn autocomplete<F, Fut>(
+    ctx: Arc<Context>,
+    mut command: InteractionCommand,
+    handler: F,
+) -> Result<()>
+where
+    F: FnOnce(Arc<Context>, AutocompleteInput) -> Fut,
+    Fut: Future<Output = Result<Vec<CommandOptionChoice>>>,
+{
+    let input = AutocompleteInput::from_command(&mut command).wrap_err("Missing focused option")?;
+    let mut choices = handler(Arc::clone(&ctx), input).await?;
+    choices.truncate(MAX_CHOICES);
+
+    command
+        .autocomplete(&ctx, choices)
+        .await
+        .wrap_err("Failed to respond with autocomplete choices")?;
+
+    Ok(())
+}
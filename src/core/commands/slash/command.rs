@@ -4,7 +4,7 @@ use twilight_interactions::command::ApplicationCommandData;
 use twilight_model::application::command::Command;
 
 use crate::{
-    core::{commands::flags::CommandFlags, Context},
+    core::{commands::CommandFlags, Context},
     util::interaction::InteractionCommand as InteractionCommandBase,
 };
 
@@ -14,16 +14,46 @@ use super::CommandResult;
 pub enum InteractionCommand {
     Chat(&'static SlashCommand),
     Message(&'static MessageCommand),
+    User(&'static UserCommand),
 }
 
 pub struct SlashCommand {
     pub create: fn() -> ApplicationCommandData,
+    pub name: &'static str,
     pub exec: fn(Arc<Context>, InteractionCommandBase) -> CommandResult,
     pub flags: CommandFlags,
+    /// Responds to autocomplete interactions of the command
+    pub autocomplete: Option<fn(Arc<Context>, InteractionCommandBase) -> CommandResult>,
+}
+
+impl InteractionCommand {
+    pub fn name(self) -> &'static str {
+        match self {
+            InteractionCommand::Chat(cmd) => cmd.name,
+            InteractionCommand::Message(cmd) => cmd.name,
+            InteractionCommand::User(cmd) => cmd.name,
+        }
+    }
+
+    pub fn flags(self) -> CommandFlags {
+        match self {
+            InteractionCommand::Chat(cmd) => cmd.flags,
+            InteractionCommand::Message(cmd) => cmd.flags,
+            InteractionCommand::User(cmd) => cmd.flags,
+        }
+    }
 }
 
 pub struct MessageCommand {
     pub create: fn() -> Command,
     pub exec: fn(Arc<Context>, InteractionCommandBase) -> CommandResult,
+    pub flags: CommandFlags,
+    pub name: &'static str,
+}
+
+pub struct UserCommand {
+    pub create: fn() -> Command,
+    pub exec: fn(Arc<Context>, InteractionCommandBase) -> CommandResult,
+    pub flags: CommandFlags,
     pub name: &'static str,
 }
@@ -0,0 +1,54 @@
+use eyre::{Result, WrapErr};
+use twilight_interactions::error::ParseError;
+
+use crate::{
+    core::{
+        commands::CommandFlags,
+        metrics::{Api, ObserveExt},
+        Context,
+    },
+    util::{ext::InteractionCommandExt, interaction::InteractionCommand, locale::t},
+};
+
+/// Tell the user that the options of the command could not be parsed.
+///
+/// The error is only visible to the user, even if the command was deferred publicly.
+pub async fn option_error(
+    ctx: &Context,
+    command: &InteractionCommand,
+    flags: CommandFlags,
+    err: ParseError,
+) -> Result<()> {
+    warn!(?err, "Failed to parse command options");
+
+    let content = t!(
+        &command.locale,
+        "error-invalid-options",
+        error = err.to_string()
+    );
+
+    if !flags.defer() {
+        command
+            .error_callback(ctx, content)
+            .await
+            .wrap_err("Failed to callback")?;
+    } else if flags.ephemeral() {
+        command
+            .error(ctx, content)
+            .await
+            .wrap_err("Failed to update")?;
+    } else {
+        ctx.interaction()
+            .delete_response(&command.token)
+            .observe(Api::Discord, "delete_response")
+            .await
+            .wrap_err("Failed to delete deferred response")?;
+
+        command
+            .error_followup(ctx, content)
+            .await
+            .wrap_err("Failed to send followup")?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,293 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use eyre::{Result, WrapErr};
+use radix_trie::TrieCommon;
+use twilight_http::client::InteractionClient;
+use twilight_model::{
+    application::{
+        command::{Command, CommandOption, CommandOptionChoiceValue, CommandType},
+        interaction::InteractionContextType,
+    },
+    id::{marker::GuildMarker, Id},
+    oauth::ApplicationIntegrationType,
+};
+
+use crate::util::locale::LOCALES;
+
+use super::{InteractionCommand, InteractionCommands};
+
+/// Where commands are registered
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CommandScope {
+    Global,
+    Guild(Id<GuildMarker>),
+}
+
+impl Display for CommandScope {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Global => f.write_str("global"),
+            Self::Guild(guild) => write!(f, "guild {guild}"),
+        }
+    }
+}
+
+/// Difference between the commands that should be registered and the ones that are
+#[derive(Default)]
+pub struct CommandDiff {
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub removed: Vec<String>,
+}
+
+impl CommandDiff {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.changed.is_empty() && self.removed.is_empty()
+    }
+}
+
+impl Display for CommandDiff {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.is_empty() {
+            return f.write_str("up to date");
+        }
+
+        let groups = [
+            ("added", &self.added),
+            ("changed", &self.changed),
+            ("removed", &self.removed),
+        ];
+
+        let mut first = true;
+
+        for (label, names) in groups {
+            if names.is_empty() {
+                continue;
+            }
+
+            if !first {
+                f.write_str("; ")?;
+            }
+
+            write!(f, "{label}: {}", names.join(", "))?;
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+impl InteractionCommands {
+    /// Definitions of all commands as they are sent to discord,
+    /// including the localizations of the catalog
+    pub fn definitions(&self) -> Vec<Command> {
+        self.0
+            .values()
+            .map(|cmd| {
+                let mut command = match cmd {
+                    InteractionCommand::Chat(cmd) => (cmd.create)().into(),
+                    InteractionCommand::Message(cmd) => (cmd.create)(),
+                    InteractionCommand::User(cmd) => (cmd.create)(),
+                };
+
+                localize_command(&mut command);
+
+                command
+            })
+            .collect()
+    }
+
+    /// Compare the commands registered in the scope against the current ones
+    pub async fn diff(
+        &self,
+        client: &InteractionClient<'_>,
+        scope: CommandScope,
+    ) -> Result<CommandDiff> {
+        let registered = Self::registered(client, scope).await?;
+
+        Ok(Self::diff_with(&self.definitions(), &registered))
+    }
+
+    /// Overwrite the commands of the scope with the current ones in bulk.
+    ///
+    /// Commands that were renamed or removed since the last registration are deleted.
+    pub async fn register(
+        &self,
+        client: &InteractionClient<'_>,
+        scope: CommandScope,
+    ) -> Result<CommandDiff> {
+        let commands = self.definitions();
+        let registered = Self::registered(client, scope).await?;
+        let diff = Self::diff_with(&commands, &registered);
+
+        info!("Interaction commands ({scope}): {diff}");
+
+        if diff.is_empty() {
+            return Ok(diff);
+        }
+
+        match scope {
+            CommandScope::Global => client.set_global_commands(&commands).await,
+            CommandScope::Guild(guild) => client.set_guild_commands(guild, &commands).await,
+        }
+        .wrap_err_with(|| format!("Failed to set interaction commands ({scope})"))?;
+
+        Ok(diff)
+    }
+
+    /// Remove all commands of the scope
+    pub async fn unregister(client: &InteractionClient<'_>, scope: CommandScope) -> Result<()> {
+        match scope {
+            CommandScope::Global => client.set_global_commands(&[]).await,
+            CommandScope::Guild(guild) => client.set_guild_commands(guild, &[]).await,
+        }
+        .wrap_err_with(|| format!("Failed to remove interaction commands ({scope})"))?;
+
+        Ok(())
+    }
+
+    async fn registered(
+        client: &InteractionClient<'_>,
+        scope: CommandScope,
+    ) -> Result<Vec<Command>> {
+        let response = match scope {
+            CommandScope::Global => client.global_commands().await,
+            CommandScope::Guild(guild) => client.guild_commands(guild).await,
+        }
+        .wrap_err_with(|| format!("Failed to request interaction commands ({scope})"))?;
+
+        response
+            .models()
+            .await
+            .wrap_err("Failed to deserialize interaction commands")
+    }
+
+    fn diff_with(commands: &[Command], registered: &[Command]) -> CommandDiff {
+        let mut diff = CommandDiff::default();
+
+        for command in commands {
+            let existing = registered
+                .iter()
+                .find(|existing| existing.name == command.name && existing.kind == command.kind);
+
+            match existing {
+                Some(existing) if differs(command, existing) => {
+                    diff.changed.push(command.name.clone())
+                }
+                Some(_) => {}
+                None => diff.added.push(command.name.clone()),
+            }
+        }
+
+        for existing in registered {
+            let kept = commands
+                .iter()
+                .any(|command| command.name == existing.name && command.kind == existing.kind);
+
+            if !kept {
+                diff.removed.push(existing.name.clone());
+            }
+        }
+
+        diff
+    }
+}
+
+/// Whether a registered command needs to be updated; ids and versions are ignored.
+///
+/// Unset fields are compared as the defaults that discord applies to them.
+#[allow(deprecated)]
+fn differs(command: &Command, registered: &Command) -> bool {
+    const CONTEXTS: &[InteractionContextType] = &[
+        InteractionContextType::Guild,
+        InteractionContextType::BotDm,
+        InteractionContextType::PrivateChannel,
+    ];
+
+    const INTEGRATION_TYPES: &[ApplicationIntegrationType] =
+        &[ApplicationIntegrationType::GuildInstall];
+
+    command.description != registered.description
+        || command.options != registered.options
+        || command.default_member_permissions != registered.default_member_permissions
+        || command.dm_permission.unwrap_or(true) != registered.dm_permission.unwrap_or(true)
+        || command.nsfw.unwrap_or(false) != registered.nsfw.unwrap_or(false)
+        || command.name_localizations != registered.name_localizations
+        || command.description_localizations != registered.description_localizations
+        || !same_entries(
+            command.contexts.as_deref().unwrap_or(CONTEXTS),
+            registered.contexts.as_deref().unwrap_or(CONTEXTS),
+        )
+        || !same_entries(
+            command
+                .integration_types
+                .as_deref()
+                .unwrap_or(INTEGRATION_TYPES),
+            registered
+                .integration_types
+                .as_deref()
+                .unwrap_or(INTEGRATION_TYPES),
+        )
+}
+
+/// Whether both slices contain the same entries, regardless of their order
+fn same_entries<T: PartialEq>(a: &[T], b: &[T]) -> bool {
+    a.len() == b.len() && a.iter().all(|entry| b.contains(entry))
+}
+
+/// Catalog key of a command name, e.g. `show-this-user-s-reports` for "Show this user's reports"
+fn catalog_key(name: &str) -> String {
+    name.split(|c: char| !c.is_alphanumeric())
+        .filter(|part| !part.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Add the catalog's localizations unless the command defines its own
+fn localize_command(command: &mut Command) {
+    let key = catalog_key(&command.name);
+
+    if command.name_localizations.is_none() {
+        command.name_localizations = LOCALES.localizations(&format!("{key}-name"));
+    }
+
+    // Context menu commands have no description
+    if command.kind == CommandType::ChatInput && command.description_localizations.is_none() {
+        command.description_localizations = LOCALES.localizations(&format!("{key}-desc"));
+    }
+
+    for option in command.options.iter_mut() {
+        localize_option(option, &key);
+    }
+}
+
+fn localize_option(option: &mut CommandOption, parent: &str) {
+    let key = format!("{parent}-{}", option.name);
+
+    if option.name_localizations.is_none() {
+        option.name_localizations = LOCALES.localizations(&format!("{key}-name"));
+    }
+
+    if option.description_localizations.is_none() {
+        option.description_localizations = LOCALES.localizations(&format!("{key}-desc"));
+    }
+
+    for choice in option.choices.iter_mut().flatten() {
+        if choice.name_localizations.is_some() {
+            continue;
+        }
+
+        let value = match choice.value {
+            CommandOptionChoiceValue::String(ref value) => value.clone(),
+            CommandOptionChoiceValue::Integer(value) => value.to_string(),
+            CommandOptionChoiceValue::Number(value) => value.to_string(),
+        };
+
+        choice.name_localizations = LOCALES.localizations(&format!("{key}-{value}"));
+    }
+
+    for option in option.options.iter_mut().flatten() {
+        localize_option(option, &key);
+    }
+}
@@ -1,25 +1,59 @@
-use std::sync::Arc;
+use std::{
+    mem,
+    path::PathBuf,
+    sync::{Arc, RwLock},
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use octocrab::Octocrab;
-use twilight_gateway::{Config, ConfigBuilder, EventTypeFlags, Intents, Shard, ShardId};
+use tokio::sync::Mutex;
+use twilight_gateway::{ConfigBuilder, EventTypeFlags, Intents, Shard, ShardId};
 use twilight_http::{client::InteractionClient, Client};
 use twilight_model::{
     gateway::{
         payload::outgoing::update_presence::UpdatePresencePayload,
         presence::{ActivityType, MinimalActivity, Status},
     },
-    id::{marker::ApplicationMarker, Id},
+    id::{
+        marker::{ApplicationMarker, GuildMarker, UserMarker},
+        Id,
+    },
 };
 
-use crate::{active::ActiveMessages, util::config::Project};
+use crate::{
+    active::ActiveMessages,
+    core::{
+        audit::AuditLog,
+        commands::{Cooldown, Cooldowns},
+        health::Health,
+        records::IssueRecords,
+        reports::PendingReports,
+    },
+    util::{
+        cache::TtlCache,
+        config::{Config, ConfigFormat, Project},
+        locale::t,
+    },
+};
 
 pub struct Context {
     pub application_id: Id<ApplicationMarker>,
-    pub config: Project,
-    // pub env_vars: EnvVars,.
+    /// Swapped out whenever the config file is reloaded; access through [`Context::config`]
+    pub config: RwLock<Arc<Config>>,
+    pub config_path: PathBuf,
+    pub config_format: ConfigFormat,
+    /// Held while the config is reloaded so that concurrent reloads don't race
+    pub reload_lock: Mutex<()>,
     pub http: Arc<Client>,
     pub github: Octocrab,
     pub active_msgs: ActiveMessages,
+    pub records: IssueRecords,
+    pub reports: Arc<PendingReports>,
+    pub audit: AuditLog,
+    pub cooldowns: Cooldowns,
+    /// Labels of repositories by their owner and name, for autocomplete suggestions
+    pub label_cache: TtlCache<(String, String), Arc<[String]>>,
+    pub health: Health,
 }
 
 impl Context {
@@ -27,8 +61,31 @@ impl Context {
         self.http.interaction(self.application_id)
     }
 
+    /// The currently active config
+    pub fn config(&self) -> Arc<Config> {
+        Arc::clone(&self.config.read().unwrap())
+    }
+
+    /// Replace the active config and return the previous one
+    pub fn swap_config(&self, config: Config) -> Arc<Config> {
+        let mut guard = self.config.write().unwrap();
+
+        mem::replace(&mut *guard, Arc::new(config))
+    }
+
+    /// The project of the guild in which an interaction or event took place
+    pub fn project(&self, guild: Option<Id<GuildMarker>>) -> Option<Arc<Project>> {
+        let guild = guild?;
+
+        self.config().project(guild).cloned()
+    }
+
     pub fn create_shard(token: String, activity: Option<String>) -> Shard {
-        let mut shard_config = ConfigBuilder::new(token, Intents::empty());
+        let intents = Intents::GUILDS
+            | Intents::GUILD_MESSAGES
+            | Intents::GUILD_MESSAGE_REACTIONS
+            | Intents::MESSAGE_CONTENT;
+        let mut shard_config = ConfigBuilder::new(token, intents);
 
         if let Some(activity) = activity {
             let activity = MinimalActivity {
@@ -45,4 +102,27 @@ impl Context {
 
         Shard::with_config(ShardId::ONE, shard_config.build())
     }
+
+    /// Register a use of a command or active message action by the user.
+    ///
+    /// Configured cooldowns take precedence over the given default.
+    /// Returns the message for the user if they are on cooldown.
+    pub fn check_cooldown(
+        &self,
+        name: &str,
+        user: Id<UserMarker>,
+        default: Option<Cooldown>,
+        locale: &str,
+    ) -> Option<String> {
+        let cooldown = self.config().cooldowns.get(name).copied().or(default)?;
+        let remaining = self.cooldowns.check(name, user, cooldown)?;
+
+        let available_at = SystemTime::now()
+            .checked_add(remaining)?
+            .duration_since(UNIX_EPOCH)
+            .ok()?
+            .as_secs();
+
+        Some(t!(locale, "error-cooldown", timestamp = available_at))
+    }
 }
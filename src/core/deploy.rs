@@ -0,0 +1,59 @@
+use std::path::Path;
+
+use eyre::{Result, WrapErr};
+use twilight_http::Client;
+use twilight_model::id::{marker::ApplicationMarker, Id};
+
+use crate::{
+    core::commands::slash::{CommandScope, InteractionCommands, INTERACTION_COMMANDS},
+    util::config::{Config, ConfigFormat},
+};
+
+/// Register the interaction commands in all scopes of the config.
+///
+/// On a dry run only the difference to the registered commands is printed.
+pub async fn register_commands(path: &Path, format: ConfigFormat, dry_run: bool) -> Result<()> {
+    let config = Config::load(path, format)?;
+    let http = Client::new(config.discord_token.expose().to_owned());
+    let client = http.interaction(application_id(&http).await?);
+
+    for scope in config.command_scopes() {
+        let diff = if dry_run {
+            INTERACTION_COMMANDS.diff(&client, scope).await?
+        } else {
+            INTERACTION_COMMANDS.register(&client, scope).await?
+        };
+
+        println!("{scope}: {diff}");
+    }
+
+    Ok(())
+}
+
+/// Remove the interaction commands globally and from every guild of the config
+pub async fn unregister_commands(path: &Path, format: ConfigFormat) -> Result<()> {
+    let config = Config::load(path, format)?;
+    let http = Client::new(config.discord_token.expose().to_owned());
+    let client = http.interaction(application_id(&http).await?);
+
+    let scopes = config.guilds().map(CommandScope::Guild);
+
+    for scope in [CommandScope::Global].into_iter().chain(scopes) {
+        InteractionCommands::unregister(&client, scope).await?;
+        println!("{scope}: removed all commands");
+    }
+
+    Ok(())
+}
+
+async fn application_id(http: &Client) -> Result<Id<ApplicationMarker>> {
+    let application = http
+        .current_user_application()
+        .await
+        .wrap_err("Failed to request application")?
+        .model()
+        .await
+        .wrap_err("Failed to deserialize application")?;
+
+    Ok(application.id)
+}
@@ -1,19 +1,42 @@
-use std::{mem, sync::Arc};
+use std::{panic::AssertUnwindSafe, sync::Arc};
 
-use eyre::Result;
+use futures::FutureExt;
 
-use crate::{core::Context, util::interaction::InteractionCommand};
+use crate::{
+    core::{
+        commands::slash::{InteractionCommand, INTERACTION_COMMANDS},
+        incident::{panic_report, Incident},
+        Context,
+    },
+    util::{ext::Authored, interaction::InteractionCommand as InteractionCommandBase},
+};
 
-pub async fn handle_autocomplete(_ctx: Arc<Context>, mut command: InteractionCommand) {
-    let name = mem::take(&mut command.data.name);
+pub async fn handle_autocomplete(ctx: Arc<Context>, command: InteractionCommandBase) {
+    let name = command.data.name.clone();
 
-    #[allow(unused, clippy::match_single_binding)]
-    let res: Result<()> = match name.as_str() {
-        _ => return error!(?name, "Unknown autocomplete command"),
+    let autocomplete = match INTERACTION_COMMANDS.command(&name) {
+        Some(InteractionCommand::Chat(cmd)) => cmd.autocomplete,
+        _ => None,
     };
 
-    #[allow(unreachable_code)]
-    if let Err(err) = res {
-        error!(?name, ?err, "Failed to process autocomplete");
+    let Some(autocomplete) = autocomplete else {
+        return error!(?name, "Unknown autocomplete command");
+    };
+
+    let user = command.user_id().ok();
+
+    let res = AssertUnwindSafe(autocomplete(Arc::clone(&ctx), command))
+        .catch_unwind()
+        .await;
+
+    match res {
+        Ok(Ok(())) => {}
+        Ok(Err(err)) => error!(?name, ?err, "Failed to process autocomplete"),
+        // Autocomplete can't show a message so the incident is only reported
+        Err(payload) => {
+            let origin = format!("autocomplete `/{name}`");
+            let err = panic_report(payload);
+            Incident::new().report(&ctx, &origin, user, &err).await;
+        }
     }
 }
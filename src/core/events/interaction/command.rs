@@ -1,13 +1,24 @@
-use std::{mem, sync::Arc};
+use std::{mem, panic::AssertUnwindSafe, sync::Arc};
 
 use eyre::Result;
+use futures::FutureExt;
 
 use crate::{
     core::{
-        commands::slash::{InteractionCommand, INTERACTION_COMMANDS},
+        audit::{self, AuditAction, AuditEntry, Operator},
+        commands::{
+            slash::{InteractionCommand, INTERACTION_COMMANDS},
+            CommandFlags,
+        },
+        incident::{panic_report, Incident},
+        metrics::METRICS,
         Context,
     },
-    util::{ext::InteractionCommandExt, interaction::InteractionCommand as InteractionCommandBase},
+    util::{
+        ext::{Authored, InteractionCommandExt},
+        interaction::InteractionCommand as InteractionCommandBase,
+        locale::t,
+    },
 };
 
 pub async fn handle_command(ctx: Arc<Context>, mut command: InteractionCommandBase) {
@@ -18,9 +29,90 @@ pub async fn handle_command(ctx: Arc<Context>, mut command: InteractionCommandBa
         None => return error!(?name, "Unknown interaction command"),
     };
 
-    match process_command(ctx, command, cmd).await {
-        Ok(_) => info!(?name, "Processed slash command"),
-        Err(err) => error!(?name, ?err, "Failed to process command"),
+    // The command is consumed by its handler but may need a response if it fails
+    let orig = command.clone();
+
+    let res = AssertUnwindSafe(process_command(Arc::clone(&ctx), command, cmd, &name))
+        .catch_unwind()
+        .await
+        .unwrap_or_else(|payload| Err(panic_report(payload)));
+
+    let outcome = match res {
+        Ok(outcome) => {
+            info!(?name, "Processed slash command");
+
+            outcome
+        }
+        Err(err) => {
+            let incident = Incident::new();
+            let content = incident.user_message(&orig.locale);
+            respond_incident(&(&*ctx, &orig), content).await;
+
+            let origin = format!("command `/{name}`");
+            incident
+                .report(&ctx, &origin, orig.user_id().ok(), &err)
+                .await;
+
+            CommandOutcome::Failed
+        }
+    };
+
+    METRICS.interaction(&name, outcome.as_str());
+}
+
+/// Show the incident to the user without replacing a response of the failed handler, e.g. an
+/// error message it already sent
+async fn respond_incident(target: &impl IncidentTarget, content: String) {
+    if target.respond(content.clone()).await.is_ok() {
+        return;
+    }
+
+    if let Err(err) = target.follow_up(content).await {
+        warn!(?err, "Failed to respond to failed command");
+    }
+}
+
+/// Interaction that the incident of a failed command is shown on
+trait IncidentTarget {
+    /// Respond to the interaction; fails if it was acknowledged already
+    async fn respond(&self, content: String) -> Result<()>;
+
+    /// Send a new message after the interaction was acknowledged
+    async fn follow_up(&self, content: String) -> Result<()>;
+}
+
+impl IncidentTarget for (&Context, &InteractionCommandBase) {
+    async fn respond(&self, content: String) -> Result<()> {
+        let (ctx, command) = *self;
+        command.error_callback(ctx, content).await?;
+
+        Ok(())
+    }
+
+    async fn follow_up(&self, content: String) -> Result<()> {
+        let (ctx, command) = *self;
+        command.error_followup(ctx, content).await?;
+
+        Ok(())
+    }
+}
+
+/// How handling a command ended, as recorded in the metrics
+enum CommandOutcome {
+    Processed,
+    Rejected,
+    Cooldown,
+    Failed,
+}
+
+impl CommandOutcome {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Processed => "processed",
+            Self::Rejected => "rejected",
+            Self::Cooldown => "cooldown",
+            Self::Failed => "failed",
+        }
     }
 }
 
@@ -28,7 +120,31 @@ async fn process_command(
     ctx: Arc<Context>,
     command: InteractionCommandBase,
     cmd: InteractionCommand,
-) -> Result<()> {
+    name: &str,
+) -> Result<CommandOutcome> {
+    if let Some(content) = check_flags(&ctx, &command, cmd.flags())? {
+        let operator = Operator::from(command.user()?);
+        info!(user = operator.name, command = name, reason = %content, "Rejected command");
+        command.error_callback(&ctx, content).await?;
+
+        let action = AuditAction::PermissionRejected {
+            attempted: format!("/{name}"),
+        };
+
+        let entry = AuditEntry::new(command.guild_id, Some(operator), action);
+        audit::record(&ctx, entry).await;
+
+        return Ok(CommandOutcome::Rejected);
+    }
+
+    if let Some(content) = ctx.check_cooldown(name, command.user_id()?, None, &command.locale) {
+        let user = command.username()?;
+        info!(user, command = name, "Command on cooldown");
+        command.error_callback(&ctx, content).await?;
+
+        return Ok(CommandOutcome::Cooldown);
+    }
+
     match cmd {
         InteractionCommand::Chat(cmd) => {
             if cmd.flags.defer() {
@@ -38,7 +154,114 @@ async fn process_command(
             (cmd.exec)(ctx, command).await?;
         }
         InteractionCommand::Message(cmd) => (cmd.exec)(ctx, command).await?,
+        InteractionCommand::User(cmd) => (cmd.exec)(ctx, command).await?,
+    }
+
+    Ok(CommandOutcome::Processed)
+}
+
+/// Returns the reason why the command may not be used, if any
+fn check_flags(
+    ctx: &Context,
+    command: &InteractionCommandBase,
+    flags: CommandFlags,
+) -> Result<Option<String>> {
+    if flags.only_guilds() && command.guild_id.is_none() {
+        return Ok(Some(t!(&command.locale, "error-only-guilds")));
+    }
+
+    let user = command.user_id()?;
+
+    if flags.only_owner() && !ctx.config().is_owner(user) {
+        return Ok(Some(t!(&command.locale, "error-only-owner")));
+    }
+
+    if flags.authority() {
+        let roles = command
+            .member
+            .as_ref()
+            .map_or(&[][..], |member| member.roles.as_slice());
+
+        let has_authority = ctx
+            .project(command.guild_id)
+            .is_some_and(|project| project.discord_config.has_authority(user, roles));
+
+        if !has_authority {
+            return Ok(Some(t!(&command.locale, "error-no-authority-command")));
+        }
+    }
+
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use eyre::eyre;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Sent {
+        Response(String),
+        Followup(String),
+    }
+
+    #[derive(Default)]
+    struct Recorder {
+        sent: Mutex<Vec<Sent>>,
+    }
+
+    impl IncidentTarget for Recorder {
+        async fn respond(&self, content: String) -> Result<()> {
+            let mut sent = self.sent.lock().unwrap();
+
+            if !sent.is_empty() {
+                return Err(eyre!("Interaction has already been acknowledged"));
+            }
+
+            sent.push(Sent::Response(content));
+
+            Ok(())
+        }
+
+        async fn follow_up(&self, content: String) -> Result<()> {
+            self.sent.lock().unwrap().push(Sent::Followup(content));
+
+            Ok(())
+        }
+    }
+
+    /// Like `/issue search`, shows its own error before returning it
+    async fn failing_handler(target: &Recorder) -> Result<()> {
+        target.respond("Failed to search issues".to_owned()).await?;
+
+        Err(eyre!("search failed"))
+    }
+
+    #[tokio::test]
+    async fn incident_keeps_handler_error() {
+        let target = Recorder::default();
+
+        if failing_handler(&target).await.is_err() {
+            respond_incident(&target, "incident".to_owned()).await;
+        }
+
+        let expected = [
+            Sent::Response("Failed to search issues".to_owned()),
+            Sent::Followup("incident".to_owned()),
+        ];
+
+        assert_eq!(*target.sent.lock().unwrap(), expected);
     }
 
-    Ok(())
+    #[tokio::test]
+    async fn incident_responds_to_unanswered_command() {
+        let target = Recorder::default();
+        respond_incident(&target, "incident".to_owned()).await;
+
+        let expected = [Sent::Response("incident".to_owned())];
+        assert_eq!(*target.sent.lock().unwrap(), expected);
+    }
 }
@@ -1,19 +1,57 @@
 use std::sync::Arc;
 
+use tracing::{field, Instrument};
 use twilight_model::application::interaction::{Interaction, InteractionData, InteractionType};
 
 use crate::{
     active::ActiveMessages,
-    core::Context,
-    util::interaction::{InteractionCommand, InteractionComponent, InteractionModal},
+    core::{
+        audit::{self, AuditAction, AuditEntry, Operator},
+        Context,
+    },
+    util::{
+        ext::{ComponentExt, ModalExt},
+        interaction::{InteractionCommand, InteractionComponent, InteractionModal},
+        locale::{t, DEFAULT_LOCALE},
+    },
 };
 
-use self::{autocomplete::handle_autocomplete, command::handle_command};
+use self::{autocomplete::handle_autocomplete, command::handle_command, modal::handle_modal};
 
 mod autocomplete;
 mod command;
+mod modal;
 
 pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
+    let span = info_span!(
+        "interaction",
+        id = %interaction.id,
+        user = field::Empty,
+        command = field::Empty,
+        custom_id = field::Empty,
+    );
+
+    if let Some(user) = interaction.author() {
+        span.record("user", user.name.as_str());
+    }
+
+    match interaction.data {
+        Some(InteractionData::ApplicationCommand(ref data)) => {
+            span.record("command", data.name.as_str());
+        }
+        Some(InteractionData::MessageComponent(ref data)) => {
+            span.record("custom_id", data.custom_id.as_str());
+        }
+        Some(InteractionData::ModalSubmit(ref data)) => {
+            span.record("custom_id", data.custom_id.as_str());
+        }
+        _ => {}
+    }
+
+    process_interaction(ctx, interaction).instrument(span).await
+}
+
+async fn process_interaction(ctx: Arc<Context>, interaction: Interaction) {
     let Interaction {
         app_permissions: permissions,
         channel_id,
@@ -21,6 +59,7 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
         guild_id,
         id,
         kind,
+        locale,
         member,
         message,
         token,
@@ -28,7 +67,7 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
         ..
     } = interaction;
 
-    {
+    let (operator, has_authority) = {
         let user_opt = member
             .as_ref()
             .and_then(|member| member.user.as_ref())
@@ -40,23 +79,25 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
             return;
         };
 
-        if !(&ctx
-            .config
-            .discord_config
-            .operator_id_as_marker()
-            .contains(&user.id))
-        {
-            let name = &user.name;
-            info!("User `{name}` attempted to use interaction but is not listed as operator");
+        let roles = member
+            .as_ref()
+            .map_or(&[][..], |member| member.roles.as_slice());
 
-            return;
-        }
-    }
+        let has_authority = ctx
+            .project(guild_id)
+            .is_some_and(|project| project.discord_config.has_authority(user.id, roles));
+
+        (Operator::from(user), has_authority)
+    };
+
+    let (user_id, name) = (operator.id, operator.name.clone());
 
     let Some(channel_id) = channel_id else {
         return warn!(?kind, "No channel id for interaction kind");
     };
 
+    let locale = locale.unwrap_or_else(|| DEFAULT_LOCALE.to_owned());
+
     match data {
         Some(InteractionData::ApplicationCommand(data)) => {
             let cmd = InteractionCommand {
@@ -65,6 +106,7 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
                 data,
                 guild_id,
                 id,
+                locale,
                 member,
                 token,
                 user,
@@ -89,12 +131,47 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
                 data,
                 guild_id,
                 id,
+                locale,
                 member,
                 message,
                 token,
                 user,
             };
 
+            if !has_authority {
+                info!("User `{name}` attempted to use a component but lacks authority");
+
+                let content = t!(&component.locale, "error-no-authority-message");
+
+                if let Err(err) = component.error_callback(&ctx, content).await {
+                    warn!(?err, "Failed to respond to component");
+                }
+
+                let action = AuditAction::PermissionRejected {
+                    attempted: component.data.custom_id,
+                };
+
+                let entry = AuditEntry::new(guild_id, Some(operator), action);
+                audit::record(&ctx, entry).await;
+
+                return;
+            }
+
+            if let Some(content) =
+                ctx.check_cooldown(&component.data.custom_id, user_id, None, &component.locale)
+            {
+                info!(
+                    "User `{name}` is on cooldown for `{}`",
+                    component.data.custom_id
+                );
+
+                if let Err(err) = component.error_callback(&ctx, content).await {
+                    warn!(?err, "Failed to respond to component");
+                }
+
+                return;
+            }
+
             ActiveMessages::handle_component(&ctx, component).await
         }
         Some(InteractionData::ModalSubmit(data)) => {
@@ -104,12 +181,37 @@ pub async fn handle_interaction(ctx: Arc<Context>, interaction: Interaction) {
                 data,
                 guild_id,
                 id,
+                locale,
                 member,
                 message,
                 token,
                 user,
             };
 
+            // Modals of commands are not attached to an active message
+            if modal.message.is_none() {
+                return handle_modal(ctx, modal).await;
+            }
+
+            if !has_authority {
+                info!("User `{name}` attempted to submit a modal but lacks authority");
+
+                let content = t!(&modal.locale, "error-no-authority-message");
+
+                if let Err(err) = modal.error_callback(&ctx, content).await {
+                    warn!(?err, "Failed to respond to modal");
+                }
+
+                let action = AuditAction::PermissionRejected {
+                    attempted: modal.data.custom_id,
+                };
+
+                let entry = AuditEntry::new(guild_id, Some(operator), action);
+                audit::record(&ctx, entry).await;
+
+                return;
+            }
+
             ActiveMessages::handle_modal(&ctx, modal).await
         }
         _ => {}
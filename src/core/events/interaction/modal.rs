@@ -0,0 +1,51 @@
+use std::{panic::AssertUnwindSafe, sync::Arc};
+
+use futures::FutureExt;
+
+use crate::{
+    active::REPORT_MODAL,
+    commands::github::handle_report_modal,
+    core::{
+        incident::{panic_report, Incident},
+        Context,
+    },
+    util::{
+        ext::{Authored, ModalExt},
+        interaction::InteractionModal,
+    },
+};
+
+/// Handle a modal that was opened by a command rather than an active message
+pub async fn handle_modal(ctx: Arc<Context>, modal: InteractionModal) {
+    let name = modal.data.custom_id.clone();
+
+    // The modal is consumed by its handler but needs a response if it fails
+    let orig = modal.clone();
+
+    let fut = match name.as_str() {
+        REPORT_MODAL => handle_report_modal(Arc::clone(&ctx), modal),
+        _ => return error!(?name, "Unknown modal"),
+    };
+
+    let res = AssertUnwindSafe(fut)
+        .catch_unwind()
+        .await
+        .unwrap_or_else(|payload| Err(panic_report(payload)));
+
+    match res {
+        Ok(_) => info!(?name, "Processed modal"),
+        Err(err) => {
+            let incident = Incident::new();
+            let content = incident.user_message(&orig.locale);
+
+            if let Err(err) = orig.error_callback(&ctx, content).await {
+                warn!(?err, "Failed to respond to failed modal");
+            }
+
+            let origin = format!("modal `{name}`");
+            incident
+                .report(&ctx, &origin, orig.user_id().ok(), &err)
+                .await;
+        }
+    }
+}
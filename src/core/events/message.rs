@@ -0,0 +1,86 @@
+use std::sync::Arc;
+
+use eyre::{Result, WrapErr};
+use twilight_http::request::channel::reaction::RequestReactionType;
+use twilight_model::channel::Message;
+
+use crate::core::{
+    audit::{self, AuditAction, AuditEntry, Operator},
+    metrics::{Api, ObserveExt},
+    Context,
+};
+
+/// Prefix for messages inside mirror threads that should be posted as github comment
+const GITHUB_PREFIX: &str = "!gh";
+
+pub async fn handle_message(ctx: Arc<Context>, msg: Message) -> Result<()> {
+    if msg.author.bot {
+        return Ok(());
+    }
+
+    let Some(content) = msg.content.strip_prefix(GITHUB_PREFIX) else {
+        return Ok(());
+    };
+
+    // e.g. `!ghost` is no command
+    if !content.is_empty() && !content.starts_with(char::is_whitespace) {
+        return Ok(());
+    }
+
+    let content = content.trim();
+
+    if content.is_empty() {
+        return Ok(());
+    }
+
+    let Some(record) = ctx.records.by_thread(msg.channel_id).await else {
+        return Ok(());
+    };
+
+    let roles = msg
+        .member
+        .as_ref()
+        .map_or(&[][..], |member| member.roles.as_slice());
+
+    let has_authority = ctx
+        .project(msg.guild_id)
+        .is_some_and(|project| project.discord_config.has_authority(msg.author.id, roles));
+
+    let operator = Some(Operator::from(&msg.author));
+
+    if !has_authority {
+        let name = &msg.author.name;
+        info!("User `{name}` attempted to comment on github but lacks authority");
+
+        let action = AuditAction::PermissionRejected {
+            attempted: GITHUB_PREFIX.to_owned(),
+        };
+
+        audit::record(&ctx, AuditEntry::new(msg.guild_id, operator, action)).await;
+
+        return Ok(());
+    }
+
+    let issue = record.issue_ref();
+    let comment = issue.comment(&ctx, content, &msg.author.name).await?;
+
+    // Registered before anything else is awaited so the sync doesn't mirror it back into the thread
+    ctx.records.add_own_comment(&issue, comment.id.0).await?;
+
+    let action = AuditAction::CommentAdded {
+        issue: issue.url(),
+        comment: comment.html_url.to_string(),
+    };
+
+    audit::record(&ctx, AuditEntry::new(msg.guild_id, operator, action)).await;
+
+    let emoji = RequestReactionType::Unicode { name: "✅" };
+
+    ctx.http
+        .create_reaction(msg.channel_id, msg.id, &emoji)
+        .observe(Api::Discord, "create_reaction")
+        .await
+        .wrap_err("Failed to react to message")?;
+
+    Ok(())
+}
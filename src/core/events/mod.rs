@@ -1,40 +1,79 @@
-use std::sync::Arc;
+use std::{panic::AssertUnwindSafe, sync::Arc};
 
 use eyre::Result;
+use futures::FutureExt;
 use tracing::instrument::WithSubscriber;
 use twilight_gateway::{
     error::{ReceiveMessageError, ReceiveMessageErrorType},
     Event, EventTypeFlags, Shard, StreamExt,
 };
 
-use self::interaction::handle_interaction;
+use self::{
+    interaction::handle_interaction, message::handle_message, reaction::handle_reaction_add,
+    thread::handle_thread_update,
+};
 
-use super::Context;
+use super::{
+    incident::{panic_report, Incident},
+    metrics::METRICS,
+    Context,
+};
 
 mod interaction;
+mod message;
+mod reaction;
+mod thread;
 
 pub async fn event_loop(ctx: Arc<Context>, shard: &mut Shard) {
     let flags = EventTypeFlags::GATEWAY_INVALIDATE_SESSION
         | EventTypeFlags::GATEWAY_RECONNECT
         | EventTypeFlags::INTERACTION_CREATE
+        | EventTypeFlags::MESSAGE_CREATE
+        | EventTypeFlags::REACTION_ADD
         | EventTypeFlags::READY
-        | EventTypeFlags::RESUMED;
+        | EventTypeFlags::RESUMED
+        | EventTypeFlags::THREAD_UPDATE;
 
     loop {
-        let event = match shard.next_event(flags).await {
+        let event = shard.next_event(flags).await;
+
+        // Update the health before spawning so that it follows the order of the events
+        ctx.health.shard_state(shard.state());
+
+        let event = match event {
             Some(Ok(event)) => event,
             Some(Err(err)) => {
                 warn!(?err, "Error: ");
+
+                if matches!(err.kind(), ReceiveMessageErrorType::Reconnect) {
+                    METRICS.gateway_reconnect("failed");
+                }
+
                 continue;
             }
             _ => continue,
         };
 
+        match event {
+            Event::GatewayReconnect => METRICS.gateway_reconnect("requested"),
+            Event::GatewayInvalidateSession(_) => METRICS.gateway_reconnect("invalidated"),
+            _ => {}
+        }
+
         let ctx = Arc::clone(&ctx);
 
         tokio::spawn(async move {
-            if let Err(err) = handle_event(ctx, event).await {
-                error!(?err, "Error while handling event");
+            let kind = event.kind();
+            let handled = AssertUnwindSafe(handle_event(Arc::clone(&ctx), event)).catch_unwind();
+
+            match handled.await {
+                Ok(Ok(())) => {}
+                Ok(Err(err)) => error!(?err, "Error while handling event"),
+                Err(payload) => {
+                    let origin = format!("event `{kind:?}`");
+                    let err = panic_report(payload);
+                    Incident::new().report(&ctx, &origin, None, &err).await;
+                }
             }
         });
     }
@@ -42,16 +81,18 @@ pub async fn event_loop(ctx: Arc<Context>, shard: &mut Shard) {
 
 async fn handle_event(ctx: Arc<Context>, event: Event) -> Result<()> {
     match event {
+        Event::GatewayClose(frame) => info!(?frame, "Gateway closed the connection"),
         Event::GatewayInvalidateSession(true) => {
             info!("Gateway invalidated session but it's reconnectable")
         }
-        Event::GatewayInvalidateSession(false) => {
-            info!("Gateway invalidated session")
-        }
+        Event::GatewayInvalidateSession(false) => info!("Gateway invalidated session"),
         Event::GatewayReconnect => info!("Gateway requested shard to reconnect"),
         Event::InteractionCreate(e) => handle_interaction(ctx, e.0).await,
+        Event::MessageCreate(e) => handle_message(ctx, e.0).await?,
+        Event::ReactionAdd(e) => handle_reaction_add(ctx, e.0).await?,
         Event::Ready(_) => info!("Shard is ready"),
         Event::Resumed => info!("Shard is resumed"),
+        Event::ThreadUpdate(e) => handle_thread_update(ctx, e.0).await?,
         _ => {}
     }
 
@@ -0,0 +1,75 @@
+use std::sync::Arc;
+
+use eyre::{Result, WrapErr};
+use twilight_model::{channel::message::EmojiReactionType, gateway::GatewayReaction};
+
+use crate::{
+    active::{ActiveMessages, CreateIssue},
+    core::{
+        metrics::{Api, ObserveExt},
+        Context,
+    },
+    util::locale::DEFAULT_LOCALE,
+};
+
+pub async fn handle_reaction_add(ctx: Arc<Context>, reaction: GatewayReaction) -> Result<()> {
+    let Some(guild) = reaction.guild_id else {
+        return Ok(());
+    };
+
+    let Some(project) = ctx.project(Some(guild)) else {
+        return Ok(());
+    };
+
+    let Some(ref config) = project.reaction else {
+        return Ok(());
+    };
+
+    if !matches_emoji(&reaction.emoji, &config.emoji) {
+        return Ok(());
+    }
+
+    let roles = reaction
+        .member
+        .as_ref()
+        .map_or(&[][..], |member| member.roles.as_slice());
+
+    if !project
+        .discord_config
+        .has_authority(reaction.user_id, roles)
+    {
+        return Ok(());
+    }
+
+    let msg = ctx
+        .http
+        .message(reaction.channel_id, reaction.message_id)
+        .observe(Api::Discord, "message")
+        .await
+        .wrap_err("Failed to receive reacted message")?
+        .model()
+        .await
+        .wrap_err("Failed to deserialize reacted message")?;
+
+    let channel = config
+        .staff_channel_as_marker()
+        .unwrap_or(reaction.channel_id);
+
+    let create_issue = CreateIssue::new(msg, guild, Arc::clone(&project), DEFAULT_LOCALE);
+
+    ActiveMessages::builder(create_issue)
+        .begin_in_channel(ctx, channel, guild)
+        .await
+        .wrap_err("Failed to begin active message")?;
+
+    Ok(())
+}
+
+fn matches_emoji(emoji: &EmojiReactionType, configured: &str) -> bool {
+    match emoji {
+        EmojiReactionType::Unicode { name } => name == configured,
+        EmojiReactionType::Custom { id, name, .. } => {
+            id.to_string() == configured || name.as_deref() == Some(configured)
+        }
+    }
+}
@@ -0,0 +1,40 @@
+use std::sync::Arc;
+
+use eyre::Result;
+use twilight_model::channel::Channel;
+
+use crate::{
+    core::{forum, records::ThreadReservation, Context},
+    util::locale::DEFAULT_LOCALE,
+};
+
+pub async fn handle_thread_update(ctx: Arc<Context>, thread: Channel) -> Result<()> {
+    let Some(project) = ctx.project(thread.guild_id) else {
+        return Ok(());
+    };
+
+    let Some(ref forum) = project.forum else {
+        return Ok(());
+    };
+
+    if thread.parent_id != Some(forum.channel_id_as_marker()) || thread.applied_tags.is_none() {
+        return Ok(());
+    }
+
+    let ThreadReservation::Reserved(reserved) = ctx.records.reserve_thread(thread.id).await else {
+        return Ok(());
+    };
+
+    let tags = forum::forum_tags(&ctx, forum).await?;
+
+    if !forum::is_accepted(&thread, &tags, forum) {
+        return Ok(());
+    }
+
+    // Applying the tag isn't an interaction so there's no locale of a user
+    let record =
+        forum::create_issue_from_post(&ctx, &thread, None, DEFAULT_LOCALE, reserved).await?;
+    info!(issue = record.url, "Created issue for accepted forum post");
+
+    Ok(())
+}
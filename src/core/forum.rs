@@ -0,0 +1,273 @@
+use eyre::{ContextCompat, Result, WrapErr};
+use octocrab::models::IssueState;
+use twilight_model::{
+    channel::{forum::ForumTag, Channel},
+    id::{marker::TagMarker, Id},
+};
+
+use crate::{
+    core::{
+        audit::{self, AuditAction, AuditEntry, Operator},
+        metrics::{Api, ObserveExt, METRICS},
+        records::{IssueRecord, ReservedThread, SourceRecord},
+        Context,
+    },
+    util::{
+        builder::{EmbedBuilder, MessageBuilder},
+        config::ForumConfig,
+        ext::ChannelExt,
+        github::{github_time, issue_body},
+        locale::t,
+    },
+};
+
+/// Discord allows at most 5 applied tags per post
+const MAX_APPLIED_TAGS: usize = 5;
+
+/// Tags that are available in the configured forum channel
+pub async fn forum_tags(ctx: &Context, forum: &ForumConfig) -> Result<Vec<ForumTag>> {
+    let channel = ctx
+        .http
+        .channel(forum.channel_id_as_marker())
+        .observe(Api::Discord, "channel")
+        .await
+        .wrap_err("Failed to request forum channel")?
+        .model()
+        .await
+        .wrap_err("Failed to deserialize forum channel")?;
+
+    Ok(channel.available_tags.unwrap_or_default())
+}
+
+fn find_tag<'t>(tags: &'t [ForumTag], name: &str) -> Option<&'t ForumTag> {
+    tags.iter().find(|tag| tag.name.eq_ignore_ascii_case(name))
+}
+
+/// Whether the post has the configured "accepted" tag applied
+pub fn is_accepted(post: &Channel, tags: &[ForumTag], forum: &ForumConfig) -> bool {
+    let Some(accepted) = find_tag(tags, &forum.accepted_tag) else {
+        return false;
+    };
+
+    post.applied_tags
+        .as_ref()
+        .is_some_and(|applied| applied.contains(&accepted.id))
+}
+
+/// Create a github issue from the starter message of a forum post.
+///
+/// The post's tags are mapped to github labels and the "accepted" tag is applied.
+/// The operator is `None` if the issue was created because the tag was applied manually.
+/// Messages into the post are shown in the given locale.
+/// The post's thread must be reserved until the issue's record is stored.
+pub async fn create_issue_from_post(
+    ctx: &Context,
+    post: &Channel,
+    operator: Option<Operator>,
+    locale: &str,
+    _reserved: ReservedThread<'_>,
+) -> Result<IssueRecord> {
+    let guild = post.guild_id.wrap_err("Missing guild of forum post")?;
+    let project = ctx
+        .project(Some(guild))
+        .wrap_err("Missing project of guild")?;
+    let forum = project.forum.as_ref().wrap_err("Missing forum config")?;
+    let tags = forum_tags(ctx, forum).await?;
+    let applied = post.applied_tags.clone().unwrap_or_default();
+
+    // The starter message of a post shares its id with the post
+    let starter = ctx
+        .http
+        .message(post.id, post.id.cast())
+        .observe(Api::Discord, "message")
+        .await
+        .wrap_err("Failed to request starter message")?
+        .model()
+        .await
+        .wrap_err("Failed to deserialize starter message")?;
+
+    let labels: Vec<_> = tags
+        .iter()
+        .filter(|tag| applied.contains(&tag.id))
+        .filter_map(|tag| forum.tag_labels.get(&tag.name))
+        .cloned()
+        .collect();
+
+    let source_url = format!("https://discord.com/channels/{guild}/{}", post.id);
+
+    let attachments: Vec<_> = starter
+        .attachments
+        .iter()
+        .map(|attachment| attachment.url.clone())
+        .collect();
+
+    let body = issue_body(
+        &starter.content,
+        &attachments,
+        Some(&starter.author.name),
+        &source_url,
+    );
+
+    let title = post.name.clone().unwrap_or_else(|| "Forum post".to_owned());
+    let github = &project.github_config;
+
+    let repo = github
+        .repository(&forum.repository)
+        .unwrap_or(&forum.repository)
+        .to_owned();
+
+    let issue = ctx
+        .github
+        .issues(&github.owner, &repo)
+        .create(title)
+        .body(body)
+        .labels(labels.clone())
+        .send()
+        .observe(Api::Github, "issues.create")
+        .await
+        .wrap_err("Failed to create issue")?;
+
+    METRICS.issue_created(&repo);
+
+    let source = SourceRecord {
+        guild,
+        channel: post.id,
+        message: starter.id,
+        author_id: Some(starter.author.id),
+        author_name: Some(starter.author.name),
+    };
+
+    let record = IssueRecord {
+        owner: github.owner.clone(),
+        repo,
+        number: issue.number,
+        url: issue.html_url.to_string(),
+        created_at: github_time(issue.created_at.timestamp()),
+        source: Some(source),
+        thread: Some(post.id),
+        last_comment: None,
+        synced_at: None,
+        own_comments: Vec::new(),
+        forum_post: true,
+        resolved: false,
+        locale: locale.to_owned(),
+    };
+
+    ctx.records.insert(record.clone()).await?;
+
+    let action = AuditAction::IssueCreated {
+        repo: record.repo.clone(),
+        issue: record.url.clone(),
+        title: issue.title.clone(),
+        labels,
+        source: Some(source_url),
+    };
+
+    audit::record(ctx, AuditEntry::new(Some(guild), operator, action)).await;
+
+    let embed = EmbedBuilder::new()
+        .title(format!("#{} {}", issue.number, issue.title))
+        .url(record.url.clone())
+        .description(t!(locale, "forum-post-linked"));
+
+    post.id
+        .create_message(ctx, &MessageBuilder::new().embed(embed), None)
+        .await
+        .wrap_err("Failed to create post message")?;
+
+    if let Some(accepted) = find_tag(&tags, &forum.accepted_tag) {
+        if !applied.contains(&accepted.id) {
+            if applied.len() >= MAX_APPLIED_TAGS {
+                warn!(post = %post.id, "Post has too many tags to apply the accepted tag");
+            } else {
+                let mut applied = applied;
+                applied.push(accepted.id);
+
+                ctx.http
+                    .update_thread(post.id)
+                    .applied_tags(Some(&applied))
+                    .observe(Api::Discord, "update_thread")
+                    .await
+                    .wrap_err("Failed to apply accepted tag")?;
+            }
+        }
+    }
+
+    Ok(record)
+}
+
+/// If the record's issue is closed, apply the "resolved" tag and archive the post
+pub async fn resolve_post(ctx: &Context, record: &IssueRecord) -> Result<()> {
+    let guild = record.source.as_ref().map(|source| source.guild);
+
+    let Some(project) = ctx.project(guild) else {
+        return Ok(());
+    };
+
+    let Some(ref forum) = project.forum else {
+        return Ok(());
+    };
+
+    let Some(post_id) = record.thread else {
+        return Ok(());
+    };
+
+    let issue_ref = record.issue_ref();
+
+    let issue = ctx
+        .github
+        .issues(&issue_ref.owner, &issue_ref.repo)
+        .get(issue_ref.number)
+        .observe(Api::Github, "issues.get")
+        .await
+        .wrap_err("Failed to request issue")?;
+
+    if !matches!(issue.state, IssueState::Closed) {
+        return Ok(());
+    }
+
+    let tags = forum_tags(ctx, forum).await?;
+
+    let post = ctx
+        .http
+        .channel(post_id)
+        .observe(Api::Discord, "channel")
+        .await
+        .wrap_err("Failed to request forum post")?
+        .model()
+        .await
+        .wrap_err("Failed to deserialize forum post")?;
+
+    let mut applied: Vec<Id<TagMarker>> = post.applied_tags.unwrap_or_default();
+
+    if let Some(resolved) = find_tag(&tags, &forum.resolved_tag) {
+        if !applied.contains(&resolved.id) {
+            if applied.len() >= MAX_APPLIED_TAGS {
+                warn!(%post_id, "Post has too many tags to apply the resolved tag");
+            } else {
+                applied.push(resolved.id);
+            }
+        }
+    }
+
+    let embed = EmbedBuilder::new()
+        .title(format!("#{} {}", issue.number, issue.title))
+        .url(record.url.clone())
+        .description(t!(&record.locale, "forum-post-resolved"));
+
+    post_id
+        .create_message(ctx, &MessageBuilder::new().embed(embed), None)
+        .await
+        .wrap_err("Failed to create post message")?;
+
+    ctx.http
+        .update_thread(post_id)
+        .applied_tags(Some(&applied))
+        .archived(true)
+        .locked(forum.lock_resolved)
+        .observe(Api::Discord, "update_thread")
+        .await
+        .wrap_err("Failed to archive forum post")?;
+
+    ctx.records.mark_resolved(&issue_ref).await
+}
@@ -0,0 +1,182 @@
+use std::{
+    net::{Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::Path,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+use eyre::{ContextCompat, Result, WrapErr};
+use octocrab::Octocrab;
+use serde::Serialize;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    time::timeout,
+};
+use twilight_gateway::ShardState;
+
+use crate::{
+    core::metrics::{Api, ObserveExt},
+    util::{
+        cache::TtlCache,
+        config::{ConfigFormat, ServerConfig},
+        constants::GITHUB_TOKEN_CHECK_TTL,
+    },
+};
+
+/// Seconds until the healthcheck gives up on the endpoint
+const HEALTHCHECK_TIMEOUT: u64 = 5;
+
+/// What the bot needs to be ready to handle interactions
+pub struct Health {
+    gateway: Mutex<Gateway>,
+    commands_registered: AtomicBool,
+    github_token: TtlCache<(), bool>,
+}
+
+impl Default for Health {
+    fn default() -> Self {
+        Self {
+            gateway: Mutex::new(Gateway {
+                state: ShardState::Disconnected {
+                    reconnect_attempts: 0,
+                },
+                active_since: None,
+            }),
+            commands_registered: AtomicBool::new(false),
+            github_token: TtlCache::new(Duration::from_secs(GITHUB_TOKEN_CHECK_TTL)),
+        }
+    }
+}
+
+impl Health {
+    /// Track the state that the shard is in after its latest event
+    pub fn shard_state(&self, state: ShardState) {
+        let mut gateway = self.gateway.lock().unwrap();
+
+        if state != ShardState::Active {
+            gateway.active_since = None;
+        } else if gateway.active_since.is_none() {
+            gateway.active_since = Some(Instant::now());
+        }
+
+        gateway.state = state;
+    }
+
+    pub fn commands_registered(&self) {
+        self.commands_registered.store(true, Ordering::Relaxed);
+    }
+
+    /// Current readiness; the github token is only verified again once its last check expired
+    pub async fn readiness(&self, github: &Octocrab) -> Readiness {
+        let (active, active_since) = {
+            let gateway = self.gateway.lock().unwrap();
+
+            (gateway.state == ShardState::Active, gateway.active_since)
+        };
+
+        let github_token = self
+            .github_token
+            .get_or_fetch((), || async {
+                let valid = match github
+                    ._get("https://api.github.com/user")
+                    .observe(Api::Github, "users.current")
+                    .await
+                {
+                    Ok(response) => response.status().is_success(),
+                    Err(err) => {
+                        warn!(?err, "Failed to verify github token");
+
+                        false
+                    }
+                };
+
+                Ok(valid)
+            })
+            .await
+            .unwrap_or(false);
+
+        Readiness {
+            gateway: active,
+            connected_secs: active_since.map(|since| since.elapsed().as_secs()),
+            github_token,
+            commands: self.commands_registered.load(Ordering::Relaxed),
+        }
+    }
+}
+
+struct Gateway {
+    state: ShardState,
+    /// Since when the shard has been active without interruption
+    active_since: Option<Instant>,
+}
+
+#[derive(Serialize)]
+pub struct Readiness {
+    gateway: bool,
+    /// Seconds since the shard became active
+    connected_secs: Option<u64>,
+    github_token: bool,
+    commands: bool,
+}
+
+impl Readiness {
+    pub fn is_ready(&self) -> bool {
+        self.gateway && self.github_token && self.commands
+    }
+}
+
+/// Probe the `/readyz` endpoint of a running bot; fails unless it is ready
+pub async fn healthcheck(path: &Path, format: ConfigFormat) -> Result<()> {
+    let server =
+        ServerConfig::load(path, format)?.wrap_err("The config has no `server` to probe")?;
+    let mut address = server.address;
+
+    // Listening on all interfaces includes the local one
+    if address.ip().is_unspecified() {
+        match address {
+            SocketAddr::V4(_) => address.set_ip(Ipv4Addr::LOCALHOST.into()),
+            SocketAddr::V6(_) => address.set_ip(Ipv6Addr::LOCALHOST.into()),
+        }
+    }
+
+    let response = timeout(Duration::from_secs(HEALTHCHECK_TIMEOUT), probe(address))
+        .await
+        .wrap_err("Readiness probe timed out")??;
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap_or((&response, ""));
+    let status = head.split_whitespace().nth(1).unwrap_or_default();
+
+    if status != "200" {
+        bail!("Not ready ({status}): {body}");
+    }
+
+    println!("Ready: {body}");
+
+    Ok(())
+}
+
+async fn probe(address: SocketAddr) -> Result<String> {
+    let mut stream = TcpStream::connect(address)
+        .await
+        .wrap_err_with(|| format!("Failed to connect to {address}"))?;
+
+    let request = format!("GET /readyz HTTP/1.1\r\nHost: {address}\r\nConnection: close\r\n\r\n");
+
+    stream
+        .write_all(request.as_bytes())
+        .await
+        .wrap_err("Failed to send request")?;
+
+    let mut response = String::new();
+
+    stream
+        .read_to_string(&mut response)
+        .await
+        .wrap_err("Failed to read response")?;
+
+    Ok(response)
+}
@@ -0,0 +1,163 @@
+use std::{
+    any::Any,
+    collections::hash_map::RandomState,
+    hash::{BuildHasher, Hash, Hasher},
+    time::SystemTime,
+};
+
+use eyre::{Report, Result, WrapErr};
+use time::OffsetDateTime;
+use twilight_model::{
+    channel::message::embed::EmbedField,
+    id::{
+        marker::{ChannelMarker, UserMarker},
+        Id,
+    },
+};
+
+use crate::{
+    core::{
+        metrics::{Api, ObserveExt},
+        Context,
+    },
+    util::{
+        builder::{EmbedBuilder, MessageBuilder},
+        constants::RED,
+        ext::ChannelExt,
+        locale::t,
+    },
+};
+
+/// Embed descriptions may not be longer than 4096 characters
+const MAX_REPORT_LEN: usize = 4000;
+
+/// An unexpected error, identified by a short id that is shown to the user
+/// so that it can be found in the logs and the ops report
+pub struct Incident {
+    id: String,
+}
+
+impl Default for Incident {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Incident {
+    pub fn new() -> Self {
+        let mut hasher = RandomState::new().build_hasher();
+        SystemTime::now().hash(&mut hasher);
+
+        Self {
+            id: format!("{:08x}", hasher.finish() as u32),
+        }
+    }
+
+    /// Message for the user whose interaction failed
+    pub fn user_message(&self, locale: &str) -> String {
+        t!(locale, "error-incident", incident = self.id.as_str())
+    }
+
+    /// Log the error and send the full report into the ops channel, or to the owner if
+    /// there is none.
+    ///
+    /// `origin` describes what failed, e.g. ``command `/issue` ``.
+    pub async fn report(
+        &self,
+        ctx: &Context,
+        origin: &str,
+        user: Option<Id<UserMarker>>,
+        err: &Report,
+    ) {
+        error!(incident = self.id, origin, ?err, "Incident");
+
+        if let Err(err) = self.send(ctx, origin, user, err).await {
+            warn!(incident = self.id, ?err, "Failed to send incident report");
+        }
+    }
+
+    async fn send(
+        &self,
+        ctx: &Context,
+        origin: &str,
+        user: Option<Id<UserMarker>>,
+        err: &Report,
+    ) -> Result<()> {
+        let Some(channel) = ops_channel(ctx).await? else {
+            return Ok(());
+        };
+
+        let mut report = format!("{err:?}");
+
+        if let Some((idx, _)) = report.char_indices().nth(MAX_REPORT_LEN) {
+            report.truncate(idx);
+            report.push('…');
+        }
+
+        let mut fields = vec![EmbedField {
+            inline: true,
+            name: "Origin".to_owned(),
+            value: origin.to_owned(),
+        }];
+
+        if let Some(user) = user {
+            fields.push(EmbedField {
+                inline: true,
+                name: "User".to_owned(),
+                value: format!("<@{user}>"),
+            });
+        }
+
+        let embed = EmbedBuilder::new()
+            .title(format!("Incident `{}`", self.id))
+            .description(format!("```\n{report}\n```"))
+            .color(RED)
+            .fields(fields)
+            .timestamp(OffsetDateTime::now_utc());
+
+        channel
+            .create_message(ctx, &MessageBuilder::new().embed(embed), None)
+            .await
+            .wrap_err("Failed to create incident message")?;
+
+        Ok(())
+    }
+}
+
+/// The configured ops channel or the DM channel of the owner
+async fn ops_channel(ctx: &Context) -> Result<Option<Id<ChannelMarker>>> {
+    let config = ctx.config();
+
+    if let Some(channel) = config.ops_channel {
+        return Ok(Some(Id::new(channel)));
+    }
+
+    let Some(owner) = config.owner_id else {
+        return Ok(None);
+    };
+
+    let channel = ctx
+        .http
+        .create_private_channel(Id::new(owner))
+        .observe(Api::Discord, "create_private_channel")
+        .await
+        .wrap_err("Failed to create private channel")?
+        .model()
+        .await
+        .wrap_err("Failed to deserialize private channel")?;
+
+    Ok(Some(channel.id))
+}
+
+/// Turn the payload of a caught panic into a report
+pub fn panic_report(payload: Box<dyn Any + Send>) -> Report {
+    let msg = match payload.downcast::<String>() {
+        Ok(msg) => *msg,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(msg) => (*msg).to_owned(),
+            Err(_) => "unknown payload".to_owned(),
+        },
+    };
+
+    eyre!("Panicked: {msg}")
+}
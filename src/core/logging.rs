@@ -1,42 +1,211 @@
-use std::fmt;
+use std::{
+    fmt::{self, Write as _},
+    fs::{self, File, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
 
+use eyre::{Result, WrapErr};
 use time::{format_description::FormatItem, macros::format_description};
 use tracing::{Event, Subscriber};
 use tracing_appender::{
     non_blocking::{NonBlocking, WorkerGuard},
-    rolling,
+    rolling::{RollingFileAppender, Rotation},
 };
 use tracing_subscriber::{
     fmt::{
         format::Writer,
         time::{FormatTime, UtcTime},
-        FmtContext, FormatEvent, FormatFields, Layer,
+        FmtContext, FormatEvent, FormatFields, FormattedFields, Layer as FmtLayer,
     },
     layer::SubscriberExt,
     registry::LookupSpan,
-    EnvFilter, FmtSubscriber,
+    EnvFilter, Layer, Registry,
 };
 
-pub fn initialize() -> WorkerGuard {
+use crate::util::config::{LogRotation, LoggingConfig};
+
+type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync>;
+
+/// Install the global subscriber as configured.
+///
+/// The returned guard flushes the log file when dropped.
+pub fn initialize(config: &LoggingConfig) -> Result<Option<WorkerGuard>> {
     let formatter = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
 
-    let file_appender = rolling::daily("./logs", "helper.log");
-    let (file_writer, guard) = NonBlocking::new(file_appender);
+    let filter = match EnvFilter::try_from_default_env() {
+        Ok(filter) => filter,
+        Err(_) => EnvFilter::try_new(&config.filter)
+            .wrap_err_with(|| format!("Invalid log filter `{}`", config.filter))?,
+    };
+
+    let mut layers: Vec<BoxedLayer> = Vec::new();
+    let mut guard = None;
+
+    if config.stdout {
+        let layer = FmtLayer::default().with_target(false);
+
+        let layer = if config.json {
+            layer.json().with_timer(UtcTime::rfc_3339()).boxed()
+        } else {
+            layer.with_timer(UtcTime::new(formatter)).boxed()
+        };
+
+        layers.push(layer);
+    }
+
+    if let Some(ref directory) = config.directory {
+        let (file_writer, file_guard) = NonBlocking::new(file_appender(config, directory)?);
+        guard = Some(file_guard);
+
+        let layer = if config.json {
+            FmtLayer::default()
+                .json()
+                .with_timer(UtcTime::rfc_3339())
+                .with_writer(file_writer)
+                .boxed()
+        } else {
+            FmtLayer::default()
+                .event_format(FileEventFormat::new(formatter))
+                .with_writer(file_writer)
+                .boxed()
+        };
+
+        layers.push(layer);
+    }
+
+    let subscriber = Registry::default().with(layers).with(filter);
+    tracing::subscriber::set_global_default(subscriber)
+        .wrap_err("Failed to set global subscriber")?;
+
+    Ok(guard)
+}
+
+fn file_appender(config: &LoggingConfig, directory: &Path) -> Result<Box<dyn Write + Send>> {
+    let rotation = match config.rotation {
+        LogRotation::Daily => Rotation::DAILY,
+        LogRotation::Hourly => Rotation::HOURLY,
+        LogRotation::Never => Rotation::NEVER,
+        LogRotation::Size(max_bytes) => {
+            let appender = SizeRollingAppender::new(
+                directory,
+                &config.file_prefix,
+                max_bytes,
+                config.max_files,
+            )
+            .wrap_err("Failed to open log file")?;
 
-    let file_layer = Layer::default()
-        .event_format(FileEventFormat::new(formatter))
-        .with_writer(file_writer);
+            return Ok(Box::new(appender));
+        }
+    };
 
-    let subscriber = FmtSubscriber::builder()
-        .with_env_filter(EnvFilter::from_default_env())
-        .with_target(false)
-        .with_timer(UtcTime::new(formatter))
-        .finish()
-        .with(file_layer);
+    let mut builder = RollingFileAppender::builder()
+        .rotation(rotation)
+        .filename_prefix(&config.file_prefix);
 
-    tracing::subscriber::set_global_default(subscriber).expect("failed to set global subscriber");
+    if let Some(max_files) = config.max_files {
+        builder = builder.max_log_files(max_files);
+    }
+
+    let appender = builder
+        .build(directory)
+        .wrap_err("Failed to open log file")?;
+
+    Ok(Box::new(appender))
+}
 
-    guard
+/// Writes into `{prefix}` and moves it to `{prefix}.1`, `{prefix}.2`, ...
+/// once it exceeds the maximum size
+struct SizeRollingAppender {
+    path: PathBuf,
+    file: File,
+    written: u64,
+    max_bytes: u64,
+    max_files: Option<usize>,
+}
+
+impl SizeRollingAppender {
+    fn new(
+        directory: &Path,
+        prefix: &str,
+        max_bytes: u64,
+        max_files: Option<usize>,
+    ) -> io::Result<Self> {
+        fs::create_dir_all(directory)?;
+        let path = directory.join(prefix);
+        let file = Self::open(&path)?;
+        let written = file.metadata()?.len();
+
+        Ok(Self {
+            path,
+            file,
+            written,
+            max_bytes,
+            max_files,
+        })
+    }
+
+    fn open(path: &Path) -> io::Result<File> {
+        OpenOptions::new().create(true).append(true).open(path)
+    }
+
+    fn rotated(&self, idx: usize) -> PathBuf {
+        let mut path = self.path.clone().into_os_string();
+        path.push(format!(".{idx}"));
+
+        PathBuf::from(path)
+    }
+
+    fn rotate(&mut self) -> io::Result<()> {
+        self.file.flush()?;
+
+        // Count the rotated files; the current one is not among them
+        let mut count = 0;
+
+        while self.rotated(count + 1).exists() {
+            count += 1;
+        }
+
+        let keep = self
+            .max_files
+            .map_or(usize::MAX, |max| max.saturating_sub(1));
+
+        for idx in (1..=count).rev() {
+            if idx >= keep {
+                fs::remove_file(self.rotated(idx))?;
+            } else {
+                fs::rename(self.rotated(idx), self.rotated(idx + 1))?;
+            }
+        }
+
+        if keep > 0 {
+            fs::rename(&self.path, self.rotated(1))?;
+        } else {
+            fs::remove_file(&self.path)?;
+        }
+
+        self.file = Self::open(&self.path)?;
+        self.written = 0;
+
+        Ok(())
+    }
+}
+
+impl Write for SizeRollingAppender {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.written > 0 && self.written + buf.len() as u64 > self.max_bytes {
+            self.rotate()?;
+        }
+
+        let written = self.file.write(buf)?;
+        self.written += written as u64;
+
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file.flush()
+    }
 }
 
 struct FileEventFormat<'f> {
@@ -73,6 +242,23 @@ where
             metadata.line().unwrap_or(0),
         )?;
 
+        // Fields of the surrounding spans, e.g. the interaction that is being handled
+        if let Some(scope) = ctx.event_scope() {
+            for span in scope.from_root() {
+                writer.write_str(span.name())?;
+
+                let extensions = span.extensions();
+
+                if let Some(fields) = extensions.get::<FormattedFields<N>>() {
+                    if !fields.is_empty() {
+                        write!(writer, "{{{fields}}}")?;
+                    }
+                }
+
+                writer.write_str(": ")?;
+            }
+        }
+
         ctx.field_format().format_fields(writer.by_ref(), event)?;
 
         writeln!(writer)
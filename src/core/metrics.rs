@@ -0,0 +1,237 @@
+use std::{
+    future::{Future, IntoFuture},
+    pin::Pin,
+    task::{ready, Context as TaskContext, Poll},
+    time::{Duration, Instant},
+};
+
+use eyre::{Result, WrapErr};
+use prometheus::{
+    Encoder, HistogramOpts, HistogramVec, IntCounterVec, IntGauge, Opts, Registry, TextEncoder,
+};
+use twilight_http::{error::ErrorType, response::ResponseFuture};
+
+lazy_static::lazy_static! {
+    pub static ref METRICS: Metrics = match Metrics::new() {
+        Ok(metrics) => metrics,
+        Err(err) => panic!("Failed to register metrics: {err:?}"),
+    };
+}
+
+/// Prometheus metrics of the bot, exposed through the `/metrics` endpoint
+pub struct Metrics {
+    registry: Registry,
+    /// Interaction commands by name and outcome
+    interactions: IntCounterVec,
+    /// Time to handle a component or modal of an active message
+    active_handling: HistogramVec,
+    /// Amount of currently tracked active messages
+    active_messages: IntGauge,
+    issues_created: IntCounterVec,
+    api_requests: HistogramVec,
+    /// Failed API requests by their status code
+    api_errors: IntCounterVec,
+    gateway_reconnects: IntCounterVec,
+}
+
+impl Metrics {
+    fn new() -> Result<Self> {
+        let interactions = IntCounterVec::new(
+            Opts::new("interactions_total", "Handled interaction commands"),
+            &["command", "outcome"],
+        )?;
+
+        let active_handling = HistogramVec::new(
+            HistogramOpts::new(
+                "active_message_handling_seconds",
+                "Time to handle components and modals of active messages",
+            ),
+            &["kind", "custom_id"],
+        )?;
+
+        let active_messages = IntGauge::new("active_messages", "Currently active messages")?;
+
+        let issues_created = IntCounterVec::new(
+            Opts::new("issues_created_total", "Created github issues"),
+            &["repo"],
+        )?;
+
+        let api_requests = HistogramVec::new(
+            HistogramOpts::new(
+                "api_request_seconds",
+                "Latency of github and discord requests",
+            ),
+            &["api", "endpoint"],
+        )?;
+
+        let api_errors = IntCounterVec::new(
+            Opts::new("api_errors_total", "Failed github and discord requests"),
+            &["api", "endpoint", "status"],
+        )?;
+
+        let gateway_reconnects = IntCounterVec::new(
+            Opts::new(
+                "gateway_reconnects_total",
+                "Reconnects of the gateway shard",
+            ),
+            &["reason"],
+        )?;
+
+        let registry = Registry::new_custom(Some("issue_bot".to_owned()), None)?;
+        registry.register(Box::new(interactions.clone()))?;
+        registry.register(Box::new(active_handling.clone()))?;
+        registry.register(Box::new(active_messages.clone()))?;
+        registry.register(Box::new(issues_created.clone()))?;
+        registry.register(Box::new(api_requests.clone()))?;
+        registry.register(Box::new(api_errors.clone()))?;
+        registry.register(Box::new(gateway_reconnects.clone()))?;
+
+        Ok(Self {
+            registry,
+            interactions,
+            active_handling,
+            active_messages,
+            issues_created,
+            api_requests,
+            api_errors,
+            gateway_reconnects,
+        })
+    }
+
+    /// All metrics in the prometheus text format
+    pub fn encode(&self) -> Result<String> {
+        let mut buf = Vec::new();
+
+        TextEncoder::new()
+            .encode(&self.registry.gather(), &mut buf)
+            .wrap_err("Failed to encode metrics")?;
+
+        String::from_utf8(buf).wrap_err("Metrics are not valid UTF-8")
+    }
+
+    pub fn interaction(&self, command: &str, outcome: &str) {
+        self.interactions
+            .with_label_values(&[command, outcome])
+            .inc();
+    }
+
+    pub fn active_handled(&self, kind: &str, custom_id: &str, elapsed: Duration) {
+        self.active_handling
+            .with_label_values(&[kind, custom_id])
+            .observe(elapsed.as_secs_f64());
+    }
+
+    pub fn active_message_added(&self) {
+        self.active_messages.inc();
+    }
+
+    pub fn active_message_removed(&self) {
+        self.active_messages.dec();
+    }
+
+    pub fn issue_created(&self, repo: &str) {
+        self.issues_created.with_label_values(&[repo]).inc();
+    }
+
+    pub fn gateway_reconnect(&self, reason: &str) {
+        self.gateway_reconnects.with_label_values(&[reason]).inc();
+    }
+
+    fn api_request(&self, api: Api, endpoint: &str, elapsed: Duration, status: Option<String>) {
+        let api = api.as_str();
+
+        self.api_requests
+            .with_label_values(&[api, endpoint])
+            .observe(elapsed.as_secs_f64());
+
+        if let Some(status) = status {
+            self.api_errors
+                .with_label_values(&[api, endpoint, &status])
+                .inc();
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub enum Api {
+    Discord,
+    Github,
+}
+
+impl Api {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Discord => "discord",
+            Self::Github => "github",
+        }
+    }
+}
+
+/// Errors of API clients that may carry an HTTP status code
+pub trait ApiError {
+    fn status(&self) -> Option<u16>;
+}
+
+impl ApiError for twilight_http::Error {
+    fn status(&self) -> Option<u16> {
+        match self.kind() {
+            ErrorType::Response { status, .. } => Some(status.get()),
+            _ => None,
+        }
+    }
+}
+
+impl ApiError for octocrab::Error {
+    fn status(&self) -> Option<u16> {
+        match self {
+            octocrab::Error::GitHub { source, .. } => Some(source.status_code.as_u16()),
+            _ => None,
+        }
+    }
+}
+
+/// Discord request whose latency and errors are recorded
+pub type DiscordFuture<T> = Observed<ResponseFuture<T>>;
+
+pub trait ObserveExt: IntoFuture + Sized {
+    /// Record the latency of the request and its status code on failure
+    fn observe(self, api: Api, endpoint: &'static str) -> Observed<Self::IntoFuture> {
+        Observed {
+            fut: Box::pin(self.into_future()),
+            api,
+            endpoint,
+            start: None,
+        }
+    }
+}
+
+impl<F: IntoFuture> ObserveExt for F {}
+
+pub struct Observed<F> {
+    fut: Pin<Box<F>>,
+    api: Api,
+    endpoint: &'static str,
+    start: Option<Instant>,
+}
+
+impl<F, T, E> Future for Observed<F>
+where
+    F: Future<Output = Result<T, E>>,
+    E: ApiError,
+{
+    type Output = F::Output;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut TaskContext<'_>) -> Poll<Self::Output> {
+        let start = *self.start.get_or_insert_with(Instant::now);
+        let res = ready!(self.fut.as_mut().poll(cx));
+
+        let status = res.as_ref().err().map(|err| match err.status() {
+            Some(status) => status.to_string(),
+            None => "none".to_owned(),
+        });
+
+        METRICS.api_request(self.api, self.endpoint, start.elapsed(), status);
+
+        Poll::Ready(res)
+    }
+}
@@ -1,7 +1,28 @@
-pub use self::{context::Context, events::event_loop};
+pub use self::{
+    check::check_config,
+    context::Context,
+    deploy::{register_commands, unregister_commands},
+    events::event_loop,
+    health::healthcheck,
+    reload::{reload_config, spawn_config_reload},
+    server::spawn_http_server,
+    sync::spawn_issue_sync,
+};
 
+pub mod audit;
 pub mod commands;
+pub mod forum;
+pub mod health;
+pub mod incident;
 pub mod logging;
+pub mod metrics;
+pub mod records;
+pub mod reports;
 
+mod check;
 mod context;
+mod deploy;
 mod events;
+mod reload;
+mod server;
+mod sync;
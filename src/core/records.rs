@@ -0,0 +1,251 @@
+use std::{collections::HashSet, path::PathBuf, sync::Mutex as StdMutex};
+
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
+use tokio::{fs, sync::Mutex};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker, UserMarker},
+    Id,
+};
+
+use crate::util::{github::IssueRef, locale::default_locale};
+
+/// Issues that were created through the bot, persisted as json
+pub struct IssueRecords {
+    path: PathBuf,
+    inner: Mutex<Vec<IssueRecord>>,
+    /// Threads whose issue is being created right now
+    reserved: StdMutex<HashSet<Id<ChannelMarker>>>,
+}
+
+pub enum ThreadReservation<'r> {
+    Reserved(ReservedThread<'r>),
+    /// The thread has an issue already
+    Existing(IssueRecord),
+    /// The thread's issue is being created by someone else
+    InProgress,
+}
+
+/// Keeps a thread reserved until it's dropped, by which time its record should be inserted
+pub struct ReservedThread<'r> {
+    records: &'r IssueRecords,
+    thread: Id<ChannelMarker>,
+}
+
+impl Drop for ReservedThread<'_> {
+    fn drop(&mut self) {
+        self.records.reserved.lock().unwrap().remove(&self.thread);
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct IssueRecord {
+    pub owner: String,
+    pub repo: String,
+    pub number: u64,
+    pub url: String,
+    #[serde(with = "time::serde::rfc3339")]
+    pub created_at: OffsetDateTime,
+    pub source: Option<SourceRecord>,
+    /// Discord thread that mirrors the issue's comments
+    pub thread: Option<Id<ChannelMarker>>,
+    /// Id of the last github comment that was mirrored into the thread
+    #[serde(default)]
+    pub last_comment: Option<u64>,
+    /// Creation date of the last mirrored comment
+    #[serde(default, with = "time::serde::rfc3339::option")]
+    pub synced_at: Option<OffsetDateTime>,
+    /// Github comments that the bot posted itself and which must not be mirrored back
+    #[serde(default)]
+    pub own_comments: Vec<u64>,
+    /// Whether the thread is a post of the configured forum channel
+    #[serde(default)]
+    pub forum_post: bool,
+    /// Whether the forum post was marked as resolved after the issue closed
+    #[serde(default)]
+    pub resolved: bool,
+    /// Locale of the user who created the issue, used for messages into its thread
+    #[serde(default = "default_locale")]
+    pub locale: String,
+}
+
+/// The discord message an issue was created from
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SourceRecord {
+    pub guild: Id<GuildMarker>,
+    pub channel: Id<ChannelMarker>,
+    pub message: Id<MessageMarker>,
+    pub author_id: Option<Id<UserMarker>>,
+    pub author_name: Option<String>,
+}
+
+impl IssueRecord {
+    pub fn issue_ref(&self) -> IssueRef {
+        IssueRef {
+            owner: self.owner.clone(),
+            repo: self.repo.clone(),
+            number: self.number,
+        }
+    }
+
+    fn is_issue(&self, issue: &IssueRef) -> bool {
+        self.number == issue.number && self.repo == issue.repo && self.owner == issue.owner
+    }
+}
+
+impl IssueRecords {
+    /// Load the records from the given path or start empty if the file does not exist
+    pub async fn load(path: PathBuf) -> Result<Self> {
+        let records = match fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .wrap_err_with(|| format!("Failed to deserialize records at {path:?}"))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => {
+                return Err(err).wrap_err_with(|| format!("Failed to read records at {path:?}"))
+            }
+        };
+
+        Ok(Self {
+            path,
+            inner: Mutex::new(records),
+            reserved: StdMutex::default(),
+        })
+    }
+
+    pub async fn insert(&self, record: IssueRecord) -> Result<()> {
+        let mut records = self.inner.lock().await;
+        records.push(record);
+
+        self.persist(&records).await
+    }
+
+    /// The record of the issue that is mirrored into the given thread
+    pub async fn by_thread(&self, thread: Id<ChannelMarker>) -> Option<IssueRecord> {
+        self.inner
+            .lock()
+            .await
+            .iter()
+            .find(|record| record.thread == Some(thread))
+            .cloned()
+    }
+
+    /// Reserve the thread so that only one issue is created for it
+    pub async fn reserve_thread(&self, thread: Id<ChannelMarker>) -> ThreadReservation<'_> {
+        let records = self.inner.lock().await;
+
+        if let Some(record) = records.iter().find(|record| record.thread == Some(thread)) {
+            return ThreadReservation::Existing(record.clone());
+        }
+
+        if !self.reserved.lock().unwrap().insert(thread) {
+            return ThreadReservation::InProgress;
+        }
+
+        ThreadReservation::Reserved(ReservedThread {
+            records: self,
+            thread,
+        })
+    }
+
+    /// All records of issues that were created from messages of the user
+    pub async fn by_author(&self, author: Id<UserMarker>) -> Vec<IssueRecord> {
+        self.inner
+            .lock()
+            .await
+            .iter()
+            .filter(|record| {
+                record
+                    .source
+                    .as_ref()
+                    .is_some_and(|source| source.author_id == Some(author))
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// All records with a mirror thread that was not resolved yet
+    pub async fn threaded(&self) -> Vec<IssueRecord> {
+        self.inner
+            .lock()
+            .await
+            .iter()
+            .filter(|record| record.thread.is_some() && !record.resolved)
+            .cloned()
+            .collect()
+    }
+
+    /// All records of forum posts whose issue has not been closed yet
+    pub async fn unresolved_posts(&self) -> Vec<IssueRecord> {
+        self.inner
+            .lock()
+            .await
+            .iter()
+            .filter(|record| record.forum_post && !record.resolved)
+            .cloned()
+            .collect()
+    }
+
+    pub async fn mark_resolved(&self, issue: &IssueRef) -> Result<()> {
+        let mut records = self.inner.lock().await;
+
+        let Some(record) = records.iter_mut().find(|record| record.is_issue(issue)) else {
+            return Ok(());
+        };
+
+        record.resolved = true;
+
+        self.persist(&records).await
+    }
+
+    /// Remember the last comment that was mirrored for the issue
+    pub async fn set_last_comment(
+        &self,
+        issue: &IssueRef,
+        comment: u64,
+        created_at: OffsetDateTime,
+    ) -> Result<()> {
+        let mut records = self.inner.lock().await;
+
+        let Some(record) = records.iter_mut().find(|record| record.is_issue(issue)) else {
+            return Ok(());
+        };
+
+        if record.last_comment.is_some_and(|last| last >= comment) {
+            return Ok(());
+        }
+
+        record.last_comment = Some(comment);
+        record.synced_at = Some(created_at);
+        record.own_comments.retain(|&own| own > comment);
+
+        self.persist(&records).await
+    }
+
+    /// Remember a comment that the bot posted on behalf of a thread member
+    pub async fn add_own_comment(&self, issue: &IssueRef, comment: u64) -> Result<()> {
+        let mut records = self.inner.lock().await;
+
+        let Some(record) = records.iter_mut().find(|record| record.is_issue(issue)) else {
+            return Ok(());
+        };
+
+        record.own_comments.push(comment);
+
+        self.persist(&records).await
+    }
+
+    async fn persist(&self, records: &[IssueRecord]) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .wrap_err("Failed to create records directory")?;
+        }
+
+        let bytes = serde_json::to_vec_pretty(records).wrap_err("Failed to serialize records")?;
+
+        fs::write(&self.path, bytes)
+            .await
+            .wrap_err_with(|| format!("Failed to write records to {:?}", self.path))
+    }
+}
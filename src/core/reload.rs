@@ -0,0 +1,154 @@
+use std::{
+    collections::HashSet,
+    path::Path,
+    sync::Arc,
+    time::{Duration, SystemTime},
+};
+
+use eyre::Result;
+use tokio::{fs, time::interval};
+
+use crate::{
+    core::{
+        commands::slash::{InteractionCommands, INTERACTION_COMMANDS},
+        Context,
+    },
+    util::config::Config,
+};
+
+/// Seconds between checking the config file for modifications
+const POLL_INTERVAL: u64 = 5;
+
+/// Read the config file again, validate it, and swap it in.
+///
+/// Reloads wait for each other, whether triggered by SIGHUP, a file change, or `/admin reload`.
+/// Commands are registered in scopes that were added before the swap and removed from scopes
+/// that are gone afterwards. If the registration fails, the previous config stays active.
+pub async fn reload_config(ctx: &Context) -> Result<Arc<Config>> {
+    let _guard = ctx.reload_lock.lock().await;
+
+    let config = Config::load(&ctx.config_path, ctx.config_format)?;
+
+    let prev_scopes: HashSet<_> = ctx.config().command_scopes().into_iter().collect();
+    let scopes: HashSet<_> = config.command_scopes().into_iter().collect();
+    let mut registered = Vec::new();
+
+    for &scope in scopes.difference(&prev_scopes) {
+        if let Err(err) = INTERACTION_COMMANDS
+            .register(&ctx.interaction(), scope)
+            .await
+        {
+            for scope in registered {
+                if let Err(err) = InteractionCommands::unregister(&ctx.interaction(), scope).await {
+                    warn!(?err, "Failed to unregister commands of rejected config");
+                }
+            }
+
+            return Err(err);
+        }
+
+        registered.push(scope);
+    }
+
+    let prev = ctx.swap_config(config);
+    let config = ctx.config();
+    config.warn_deprecated();
+
+    if prev.discord_token != config.discord_token || prev.github_token != config.github_token {
+        warn!("Changed tokens only take effect after a restart");
+    }
+
+    if prev.server != config.server {
+        warn!("Changed server settings only take effect after a restart");
+    }
+
+    if prev.logging != config.logging {
+        warn!("Changed logging settings only take effect after a restart");
+    }
+
+    if prev.records_path != config.records_path || prev.reports_path != config.reports_path {
+        warn!("Changed records or reports paths only take effect after a restart");
+    }
+
+    // The new config is active already so failures are only logged
+    for &scope in prev_scopes.difference(&scopes) {
+        if let Err(err) = InteractionCommands::unregister(&ctx.interaction(), scope).await {
+            warn!(?err, "Failed to unregister commands of a removed scope");
+        }
+    }
+
+    info!("Reloaded config");
+
+    Ok(config)
+}
+
+/// Reload the config on SIGHUP or whenever the config file is modified
+pub fn spawn_config_reload(ctx: Arc<Context>) {
+    tokio::spawn(async move {
+        let mut interval = interval(Duration::from_secs(POLL_INTERVAL));
+        let mut last_modified = modified(&ctx.config_path).await;
+        let mut hangup = Hangup::new();
+
+        loop {
+            tokio::select! {
+                _ = hangup.recv() => info!("Received SIGHUP"),
+                _ = interval.tick() => {
+                    if modified(&ctx.config_path).await == last_modified {
+                        continue;
+                    }
+
+                    info!("Config file was modified");
+                }
+            }
+
+            last_modified = modified(&ctx.config_path).await;
+
+            if let Err(err) = reload_config(&ctx).await {
+                error!(?err, "Failed to reload config; keeping the previous one");
+            }
+        }
+    });
+}
+
+async fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).await.ok()?.modified().ok()
+}
+
+/// Listener for SIGHUP; created once so that no signal is missed between two receives
+struct Hangup {
+    #[cfg(unix)]
+    signal: Option<tokio::signal::unix::Signal>,
+}
+
+impl Hangup {
+    #[cfg(unix)]
+    fn new() -> Self {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let signal = signal(SignalKind::hangup())
+            .map_err(|err| warn!(?err, "Failed to listen for SIGHUP"))
+            .ok();
+
+        Self { signal }
+    }
+
+    #[cfg(not(unix))]
+    fn new() -> Self {
+        Self {}
+    }
+
+    #[cfg(unix)]
+    async fn recv(&mut self) {
+        match self.signal {
+            Some(ref mut signal) => {
+                signal.recv().await;
+            }
+            None => std::future::pending().await,
+        }
+    }
+
+    #[cfg(not(unix))]
+    async fn recv(&mut self) {
+        std::future::pending().await
+    }
+}
@@ -0,0 +1,104 @@
+use std::path::PathBuf;
+
+use eyre::{Result, WrapErr};
+use serde::{Deserialize, Serialize};
+use tokio::{fs, sync::Mutex};
+use twilight_model::id::{
+    marker::{ChannelMarker, GuildMarker, MessageMarker},
+    Id,
+};
+
+use crate::active::ReportSubmission;
+
+/// Reports that wait in a queue channel for their review, persisted as json so that
+/// their queue messages can be picked up again after a restart
+pub struct PendingReports {
+    path: PathBuf,
+    inner: Mutex<Vec<PendingReport>>,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PendingReport {
+    /// Queue message of the report
+    pub message: Id<MessageMarker>,
+    pub channel: Id<ChannelMarker>,
+    pub guild: Id<GuildMarker>,
+    pub report: ReportSubmission,
+}
+
+impl PendingReports {
+    /// Load the reports from the given path or start empty if the file does not exist
+    pub async fn load(path: PathBuf) -> Result<Self> {
+        let reports = match fs::read(&path).await {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .wrap_err_with(|| format!("Failed to deserialize reports at {path:?}"))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => {
+                return Err(err).wrap_err_with(|| format!("Failed to read reports at {path:?}"))
+            }
+        };
+
+        Ok(Self {
+            path,
+            inner: Mutex::new(reports),
+        })
+    }
+
+    pub async fn all(&self) -> Vec<PendingReport> {
+        self.inner.lock().await.clone()
+    }
+
+    pub async fn insert(&self, report: PendingReport) -> Result<()> {
+        let mut reports = self.inner.lock().await;
+        reports.push(report);
+
+        self.persist(&reports).await
+    }
+
+    /// Overwrite the report of the given queue message, e.g. after it was edited
+    pub async fn update(
+        &self,
+        message: Id<MessageMarker>,
+        report: &ReportSubmission,
+    ) -> Result<()> {
+        let mut reports = self.inner.lock().await;
+
+        let Some(pending) = reports
+            .iter_mut()
+            .find(|pending| pending.message == message)
+        else {
+            return Ok(());
+        };
+
+        pending.report = report.clone();
+
+        self.persist(&reports).await
+    }
+
+    /// Remove the report of the given queue message once it was reviewed
+    pub async fn remove(&self, message: Id<MessageMarker>) -> Result<()> {
+        let mut reports = self.inner.lock().await;
+        let len = reports.len();
+        reports.retain(|pending| pending.message != message);
+
+        if reports.len() == len {
+            return Ok(());
+        }
+
+        self.persist(&reports).await
+    }
+
+    async fn persist(&self, reports: &[PendingReport]) -> Result<()> {
+        if let Some(parent) = self.path.parent() {
+            fs::create_dir_all(parent)
+                .await
+                .wrap_err("Failed to create reports directory")?;
+        }
+
+        let bytes = serde_json::to_vec_pretty(reports).wrap_err("Failed to serialize reports")?;
+
+        fs::write(&self.path, bytes)
+            .await
+            .wrap_err_with(|| format!("Failed to write reports to {:?}", self.path))
+    }
+}
@@ -0,0 +1,71 @@
+use std::{net::SocketAddr, sync::Arc};
+
+use axum::{
+    extract::State,
+    http::{header::CONTENT_TYPE, StatusCode},
+    response::{IntoResponse, Response},
+    routing::get,
+    Json, Router,
+};
+use eyre::{Result, WrapErr};
+use tokio::net::TcpListener;
+
+use crate::core::{metrics::METRICS, Context};
+
+/// Serve the HTTP endpoints in the background.
+///
+/// The address is determined on startup and is not affected by config reloads.
+pub fn spawn_http_server(ctx: Arc<Context>, address: SocketAddr) {
+    tokio::spawn(async move {
+        if let Err(err) = serve(ctx, address).await {
+            error!(?err, "HTTP server stopped");
+        }
+    });
+}
+
+async fn serve(ctx: Arc<Context>, address: SocketAddr) -> Result<()> {
+    let router = Router::new()
+        .route("/metrics", get(metrics))
+        .route("/healthz", get(healthz))
+        .route("/readyz", get(readyz))
+        .with_state(ctx);
+
+    let listener = TcpListener::bind(address)
+        .await
+        .wrap_err_with(|| format!("Failed to bind HTTP server to {address}"))?;
+
+    info!("Serving HTTP endpoints on {address}");
+
+    axum::serve(listener, router)
+        .await
+        .wrap_err("Failed to serve HTTP endpoints")
+}
+
+async fn metrics() -> Response {
+    match METRICS.encode() {
+        Ok(body) => ([(CONTENT_TYPE, "text/plain; version=0.0.4")], body).into_response(),
+        Err(err) => {
+            warn!(?err, "Failed to encode metrics");
+
+            StatusCode::INTERNAL_SERVER_ERROR.into_response()
+        }
+    }
+}
+
+/// The process is alive
+async fn healthz() -> &'static str {
+    "ok"
+}
+
+/// The shard is connected, the github token is valid, and commands are registered
+async fn readyz(State(ctx): State<Arc<Context>>) -> Response {
+    let readiness = ctx.health.readiness(&ctx.github).await;
+
+    let status = if readiness.is_ready() {
+        StatusCode::OK
+    } else {
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (status, Json(readiness)).into_response()
+}
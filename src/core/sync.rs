@@ -0,0 +1,156 @@
+use std::{sync::Arc, time::Duration};
+
+use eyre::{Result, WrapErr};
+use octocrab::models::issues::Comment;
+use serde::Serialize;
+use time::OffsetDateTime;
+use tokio::time::interval;
+use twilight_model::channel::message::embed::EmbedAuthor;
+
+use crate::{
+    core::{
+        forum,
+        metrics::{Api, ObserveExt},
+        records::IssueRecord,
+        Context,
+    },
+    util::{
+        builder::{EmbedBuilder, MessageBuilder},
+        ext::ChannelExt,
+        github::github_time,
+        locale::t,
+    },
+};
+
+/// Seconds between polls if no thread config is given
+const DEFAULT_POLL_INTERVAL: u64 = 60;
+
+/// Periodically mirror new github comments into the issues' discord threads
+/// and resolve forum posts whose issue was closed.
+///
+/// The poll interval is determined on startup and is not affected by config reloads.
+pub fn spawn_issue_sync(ctx: Arc<Context>) {
+    // Poll as often as the most eager project wants to
+    let poll_interval = ctx
+        .config()
+        .projects
+        .iter()
+        .filter_map(|project| project.threads.as_ref())
+        .map(|threads| threads.poll_interval)
+        .min()
+        .unwrap_or(DEFAULT_POLL_INTERVAL);
+
+    let period = Duration::from_secs(poll_interval.max(10));
+
+    tokio::spawn(async move {
+        let mut interval = interval(period);
+
+        loop {
+            interval.tick().await;
+
+            for record in ctx.records.threaded().await {
+                if let Err(err) = mirror_comments(&ctx, &record).await {
+                    warn!(issue = %record.url, ?err, "Failed to mirror comments");
+                }
+            }
+
+            for record in ctx.records.unresolved_posts().await {
+                if let Err(err) = forum::resolve_post(&ctx, &record).await {
+                    warn!(issue = %record.url, ?err, "Failed to resolve forum post");
+                }
+            }
+        }
+    });
+}
+
+async fn mirror_comments(ctx: &Context, record: &IssueRecord) -> Result<()> {
+    let Some(thread) = record.thread else {
+        return Ok(());
+    };
+
+    let issue = record.issue_ref();
+
+    let route = format!(
+        "/repos/{}/{}/issues/{}/comments",
+        issue.owner, issue.repo, issue.number
+    );
+
+    let params = CommentsParams {
+        per_page: 100,
+        since: record.synced_at,
+    };
+
+    let comments: Vec<Comment> = ctx
+        .github
+        .get(route, Some(&params))
+        .observe(Api::Github, "issues.list_comments")
+        .await
+        .wrap_err("Failed to request comments")?;
+
+    let new_comments = comments
+        .into_iter()
+        .filter(|comment| record.last_comment.map_or(true, |last| comment.id.0 > last));
+
+    for comment in new_comments {
+        // Comments posted through `!gh` are in the thread already
+        if !record.own_comments.contains(&comment.id.0) {
+            let builder = MessageBuilder::new().embed(comment_embed(&comment, &record.locale));
+
+            thread
+                .create_message(ctx, &builder, None)
+                .await
+                .wrap_err("Failed to mirror comment")?;
+        }
+
+        ctx.records
+            .set_last_comment(
+                &issue,
+                comment.id.0,
+                github_time(comment.created_at.timestamp()),
+            )
+            .await?;
+    }
+
+    Ok(())
+}
+
+/// Query of the issue comments endpoint; requested manually because octocrab's
+/// `since` only takes chrono timestamps
+#[derive(Serialize)]
+struct CommentsParams {
+    per_page: u8,
+    #[serde(
+        with = "time::serde::rfc3339::option",
+        skip_serializing_if = "Option::is_none"
+    )]
+    since: Option<OffsetDateTime>,
+}
+
+fn comment_embed(comment: &Comment, locale: &str) -> EmbedBuilder {
+    let author = EmbedAuthor {
+        icon_url: Some(comment.user.avatar_url.to_string()),
+        name: comment.user.login.clone(),
+        proxy_icon_url: None,
+        url: Some(comment.user.html_url.to_string()),
+    };
+
+    let mut body = comment.body.clone().unwrap_or_default();
+
+    if body.len() > 4000 {
+        let mut end = 4000;
+
+        while !body.is_char_boundary(end) {
+            end -= 1;
+        }
+
+        body.truncate(end);
+        body.push('…');
+    }
+
+    EmbedBuilder::new()
+        .author(author)
+        .description(body)
+        .url(comment.html_url.to_string())
+        .title(t!(locale, "thread-new-comment"))
+        .timestamp(github_time(comment.created_at.timestamp()))
+}
@@ -5,13 +5,12 @@ extern crate eyre;
 extern crate tracing;
 
 use std::{
-    fs::File,
-    io::Read,
     path::PathBuf,
-    sync::{Arc, LazyLock, OnceLock},
+    sync::{Arc, RwLock},
+    time::Duration,
 };
 
-use clap::{parser::Values, Arg, Command};
+use clap::{parser::Values, Arg, ArgAction, Command};
 use eyre::{ContextCompat, Result, WrapErr};
 use futures::StreamExt;
 use octocrab::OctocrabBuilder;
@@ -19,45 +18,129 @@ use tokio::signal;
 use twilight_gateway::CloseFrame;
 use twilight_http::Client;
 use twilight_model::channel::message::AllowedMentions;
-use util::config::{self, Project};
+use util::{
+    cache::TtlCache,
+    config::{Config, ConfigFormat},
+    constants::AUTOCOMPLETE_TTL,
+    locale::LOCALES,
+};
 
-use crate::core::{commands::slash::INTERACTION_COMMANDS, event_loop, logging, Context};
+use crate::{
+    active::ReportReview,
+    core::{
+        check_config, commands::slash::INTERACTION_COMMANDS, event_loop, health::Health,
+        healthcheck, logging, records::IssueRecords, register_commands, reports::PendingReports,
+        spawn_config_reload, spawn_http_server, spawn_issue_sync, unregister_commands, Context,
+    },
+};
 
 mod active;
 mod commands;
 mod core;
 mod util;
 
-static CONFIG: OnceLock<Project> = OnceLock::new();
-
 #[tokio::main]
 async fn main() -> Result<()> {
-    let command = Command::new("issue-bot").arg(
-        Arg::new("config")
-            .long("configuration")
-            .short('c')
-            .help("Configuration file"),
-    );
+    // Secrets of the config may refer to variables of a `.env` file
+    if let Err(err) = dotenvy::dotenv() {
+        if !err.not_found() {
+            return Err(err).wrap_err("Failed to load .env file");
+        }
+    }
+
+    // Fail early if multiple commands share a name
+    lazy_static::initialize(&INTERACTION_COMMANDS);
+
+    // Fail early if the localization catalog is invalid
+    lazy_static::initialize(&LOCALES);
+
+    let command = Command::new("issue-bot")
+        .arg(
+            Arg::new("config")
+                .long("configuration")
+                .short('c')
+                .global(true)
+                .help("Configuration file"),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .global(true)
+                .value_parser(ConfigFormat::NAMES)
+                .help("Format of the configuration file; detected by its extension by default"),
+        )
+        .subcommand(
+            Command::new("check-config")
+                .about("Verify the configuration, its tokens, repositories, and labels"),
+        )
+        .subcommand(
+            Command::new("register-commands")
+                .about("Register the interaction commands and remove stale ones")
+                .arg(
+                    Arg::new("dry-run")
+                        .long("dry-run")
+                        .action(ArgAction::SetTrue)
+                        .help("Only print which commands would be added, changed, or removed"),
+                ),
+        )
+        .subcommand(
+            Command::new("unregister-commands")
+                .about("Remove all interaction commands globally and from the configured guilds"),
+        )
+        .subcommand(
+            Command::new("healthcheck")
+                .about("Probe the `/readyz` endpoint of the running bot; fails unless it's ready"),
+        )
+        .subcommand(
+            Command::new("print-config-schema")
+                .about("Print the JSON schema of the configuration for editor completion"),
+        );
     let matches = command.get_matches();
+
+    if let Some(("print-config-schema", _)) = matches.subcommand() {
+        let schema = schemars::schema_for!(Config);
+        let schema =
+            serde_json::to_string_pretty(&schema).wrap_err("Failed to serialize schema")?;
+        println!("{schema}");
+
+        return Ok(());
+    }
+
     let path = matches
         .get_one::<String>("config")
         .wrap_err("Failed to get a match for --configuration")?;
     let path = PathBuf::from(path);
-    let _log_worker_guard = logging::initialize();
 
-    // let env_vars = EnvVars::read()?;
-    let mut config_file = File::open(path)?;
-    let mut config_str = String::new();
-    let _ = config_file.read_to_string(&mut config_str)?;
-    let config = ron::de::from_str::<Project>(&config_str)?;
+    let format = match matches.get_one::<String>("format") {
+        Some(format) => format.parse()?,
+        None => ConfigFormat::from_path(&path),
+    };
+
+    match matches.subcommand() {
+        Some(("check-config", _)) => return check_config(&path, format).await,
+        Some(("register-commands", args)) => {
+            return register_commands(&path, format, args.get_flag("dry-run")).await
+        }
+        Some(("unregister-commands", _)) => return unregister_commands(&path, format).await,
+        Some(("healthcheck", _)) => return healthcheck(&path, format).await,
+        _ => {}
+    }
+
+    let config = Config::load(&path, format)?;
+    let _log_worker_guard = logging::initialize(&config.logging)?;
+    config.warn_deprecated();
+
+    let titles: Vec<_> = config
+        .projects
+        .iter()
+        .map(|project| project.title.as_str())
+        .collect();
 
-    println!("Bot initialized for project: {}", config.title);
+    println!("Bot initialized for projects: {}", titles.join(", "));
     info!("Configuration: {:?}", config);
-    CONFIG.get_or_init(|| ron::de::from_str::<Project>(&config_str).unwrap());
-    // info!("Issue labels: {:?}", env_vars.issue_labels);
 
     let github = OctocrabBuilder::new()
-        .personal_token(config.github_config.token.to_string())
+        .personal_token(config.github_token.expose().to_owned())
         .build()
         .wrap_err("Failed to build github client")?;
 
@@ -67,7 +150,7 @@ async fn main() -> Result<()> {
     };
 
     let http = Client::builder()
-        .token(CONFIG.get().unwrap().discord_config.token.to_string())
+        .token(config.discord_token.expose().to_owned())
         .remember_invalid_token(false)
         .default_allowed_mentions(mentions)
         .build();
@@ -81,26 +164,49 @@ async fn main() -> Result<()> {
         .wrap_err("Failed to deserialize current user")?;
 
     let http = Arc::new(http);
-    let mut shard =
-        Context::create_shard(CONFIG.get().unwrap().discord_config.token.to_string(), None);
+    let mut shard = Context::create_shard(config.discord_token.expose().to_owned(), None);
 
-    let guild_id =
-        twilight_model::id::Id::new(CONFIG.get().unwrap().discord_config.guild_id as u64);
+    let records = IssueRecords::load(config.records_path.clone())
+        .await
+        .wrap_err("Failed to load issue records")?;
+
+    let reports = PendingReports::load(config.reports_path.clone())
+        .await
+        .wrap_err("Failed to load pending reports")?;
 
     let ctx = Context {
         application_id: current_user.id.cast(),
-        config,
+        config: RwLock::new(Arc::new(config)),
+        config_path: path,
+        config_format: format,
+        reload_lock: Default::default(),
         http,
         github,
         active_msgs: Default::default(),
+        records,
+        reports: Arc::new(reports),
+        audit: Default::default(),
+        cooldowns: Default::default(),
+        label_cache: TtlCache::new(Duration::from_secs(AUTOCOMPLETE_TTL)),
+        health: Health::default(),
     };
 
-    INTERACTION_COMMANDS
-        .register(&ctx.interaction(), guild_id)
-        .await
-        .wrap_err("Failed to register interaction commands")?;
+    for scope in ctx.config().command_scopes() {
+        INTERACTION_COMMANDS
+            .register(&ctx.interaction(), scope)
+            .await?;
+    }
+
+    ctx.health.commands_registered();
 
     let ctx = Arc::new(ctx);
+    ReportReview::resume_pending(Arc::clone(&ctx)).await;
+    spawn_issue_sync(Arc::clone(&ctx));
+    spawn_config_reload(Arc::clone(&ctx));
+
+    if let Some(ref server) = ctx.config().server {
+        spawn_http_server(Arc::clone(&ctx), server.address);
+    }
 
     tokio::select! {
         _ = event_loop(ctx, &mut shard) => warn!("Event loop ended"),
@@ -42,7 +42,6 @@ impl EmbedBuilder {
         self.0
     }
 
-    #[allow(unused)]
     pub fn author(mut self, author: impl Into<EmbedAuthor>) -> Self {
         self.0.author = Some(author.into());
 
@@ -68,7 +67,6 @@ impl EmbedBuilder {
         self
     }
 
-    #[allow(unused)]
     pub fn footer(mut self, footer: impl IntoEmbedFooter) -> Self {
         self.0.footer = Some(footer.into());
 
@@ -93,7 +91,6 @@ impl EmbedBuilder {
         self
     }
 
-    #[allow(unused)]
     pub fn timestamp(mut self, timestamp: OffsetDateTime) -> Self {
         self.0.timestamp = Timestamp::from_secs(timestamp.unix_timestamp()).ok();
 
@@ -124,7 +121,6 @@ impl EmbedBuilder {
         self
     }
 
-    #[allow(unused)]
     pub fn url(mut self, url: impl Into<String>) -> Self {
         self.0.url = Some(url.into());
 
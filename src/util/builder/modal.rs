@@ -74,7 +74,6 @@ impl TextInputBuilder {
         self
     }
 
-    #[allow(unused)]
     pub fn min_len(mut self, len: u16) -> Self {
         self.input.min_length = Some(len);
 
@@ -88,14 +87,12 @@ impl TextInputBuilder {
     }
 
     /// Defaults to `TextInputStyle::Short`
-    #[allow(unused)]
     pub fn style(mut self, style: TextInputStyle) -> Self {
         self.input.style = style;
 
         self
     }
 
-    #[allow(unused)]
     /// Use this as default input. Renders the placeholder useless.
     pub fn value(mut self, value: impl Into<String>) -> Self {
         self.input.value = Some(value.into());
@@ -103,7 +100,6 @@ impl TextInputBuilder {
         self
     }
 
-    #[allow(unused)]
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.input.placeholder = Some(placeholder.into());
 
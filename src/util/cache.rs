@@ -0,0 +1,58 @@
+use std::{
+    collections::HashMap,
+    future::Future,
+    hash::Hash,
+    sync::Mutex,
+    time::{Duration, Instant},
+};
+
+use eyre::Result;
+
+/// Values that are fetched again once they're older than the time-to-live.
+///
+/// Meant for autocomplete suggestions that would otherwise hit an API on every keystroke.
+pub struct TtlCache<K, V> {
+    ttl: Duration,
+    entries: Mutex<HashMap<K, (Instant, V)>>,
+}
+
+impl<K: Eq + Hash, V: Clone> TtlCache<K, V> {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: Mutex::default(),
+        }
+    }
+
+    /// The value of the key if it has not expired yet
+    pub fn get(&self, key: &K) -> Option<V> {
+        let entries = self.entries.lock().unwrap();
+
+        entries
+            .get(key)
+            .filter(|(inserted, _)| inserted.elapsed() < self.ttl)
+            .map(|(_, value)| value.clone())
+    }
+
+    pub fn insert(&self, key: K, value: V) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.retain(|_, (inserted, _)| inserted.elapsed() < self.ttl);
+        entries.insert(key, (Instant::now(), value));
+    }
+
+    /// The cached value of the key or, if missing or expired, the freshly fetched one
+    pub async fn get_or_fetch<F, Fut>(&self, key: K, fetch: F) -> Result<V>
+    where
+        F: FnOnce() -> Fut,
+        Fut: Future<Output = Result<V>>,
+    {
+        if let Some(value) = self.get(&key) {
+            return Ok(value);
+        }
+
+        let value = fetch().await?;
+        self.insert(key, value.clone());
+
+        Ok(value)
+    }
+}
@@ -1,45 +1,562 @@
-use std::collections::HashMap;
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Formatter, Result as FmtResult},
+    fs,
+    net::SocketAddr,
+    path::{Path, PathBuf},
+    str::FromStr,
+    sync::Arc,
+};
 
-use serde::{Deserialize, Serialize};
+use eyre::{Report, Result, WrapErr};
+use schemars::JsonSchema;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use twilight_model::id::{
-    marker::{GuildMarker, UserMarker},
+    marker::{ChannelMarker, GuildMarker, RoleMarker, UserMarker},
     Id,
 };
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+use crate::{
+    core::commands::{slash::CommandScope, Cooldown},
+    util::secret::{Secret, SecretReader},
+};
+
+/// Settings of the bot that are shared by all of its projects
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct Config {
+    pub discord_token: Secret,
+    pub github_token: Secret,
+    /// User that may use owner-only commands
+    #[serde(default)]
+    pub owner_id: Option<u64>,
+    /// Channel that full reports of failed interactions are posted into; the owner receives
+    /// them as DM otherwise
+    #[serde(default)]
+    pub ops_channel: Option<u64>,
+    pub projects: Vec<Arc<Project>>,
+    /// Overrides the cooldowns of commands or active message actions by their name
+    #[serde(default)]
+    pub cooldowns: HashMap<String, Cooldown>,
+    /// Register commands globally instead of in the guilds of each project
+    #[serde(default)]
+    pub global_commands: bool,
+    #[serde(default = "default_records_path")]
+    pub records_path: PathBuf,
+    /// Reports that wait for their review
+    #[serde(default = "default_reports_path")]
+    pub reports_path: PathBuf,
+    /// Append-only JSONL file of all state-changing actions; `None` disables it
+    #[serde(default = "default_audit_path")]
+    pub audit_path: Option<PathBuf>,
+    /// Serve HTTP endpoints like `/metrics`
+    #[serde(default)]
+    pub server: Option<ServerConfig>,
+    #[serde(default)]
+    pub logging: LoggingConfig,
+    /// Whether the config was given in the single-project format of older versions
+    #[serde(skip)]
+    pub legacy_format: bool,
+}
+
+fn default_records_path() -> PathBuf {
+    PathBuf::from("./data/issues.json")
+}
+
+fn default_reports_path() -> PathBuf {
+    PathBuf::from("./data/reports.json")
+}
+
+fn default_audit_path() -> Option<PathBuf> {
+    Some(PathBuf::from("./data/audit.jsonl"))
+}
+
+impl Config {
+    /// Read and validate the config file
+    pub fn load(path: &Path, format: ConfigFormat) -> Result<Self> {
+        let content = fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read config file at {path:?}"))?;
+
+        let config = Self::parse(&content, format)
+            .wrap_err_with(|| format!("Failed to deserialize {format} config at {path:?}"))?;
+        config.validate().wrap_err("Invalid config")?;
+
+        Ok(config)
+    }
+
+    fn parse(content: &str, format: ConfigFormat) -> Result<Self> {
+        SecretReader::new(format).read(|| Self::deserialize_format(content, format))
+    }
+
+    fn deserialize_format(content: &str, format: ConfigFormat) -> Result<Self> {
+        match format {
+            ConfigFormat::Ron => match parse_ron::<Self>(content) {
+                Ok(config) => Ok(config),
+                // Configs of older versions consist of a single `Project(...)`
+                Err(err) => match parse_ron::<LegacyConfig>(content) {
+                    Ok(legacy) => Ok(legacy.into()),
+                    Err(_) => Err(err),
+                },
+            },
+            _ => deserialize(content, format),
+        }
+    }
+
+    /// Log warnings about deprecated parts of the config; logging must be initialized
+    pub fn warn_deprecated(&self) {
+        if self.legacy_format {
+            warn!(
+                "The config consists of a single `Project(...)` which is deprecated; \
+                see the README on how to migrate it to `Config(...)`"
+            );
+        }
+    }
+
+    /// The project that the guild belongs to
+    pub fn project(&self, guild: Id<GuildMarker>) -> Option<&Arc<Project>> {
+        self.projects
+            .iter()
+            .find(|project| project.discord_config.guilds.contains(&guild.get()))
+    }
+
+    /// All guilds of all projects
+    pub fn guilds(&self) -> impl Iterator<Item = Id<GuildMarker>> + '_ {
+        self.projects
+            .iter()
+            .flat_map(|project| project.discord_config.guilds_as_marker())
+    }
+
+    /// Scopes in which interaction commands are registered
+    pub fn command_scopes(&self) -> Vec<CommandScope> {
+        if self.global_commands {
+            vec![CommandScope::Global]
+        } else {
+            self.guilds().map(CommandScope::Guild).collect()
+        }
+    }
+
+    pub fn is_owner(&self, user: Id<UserMarker>) -> bool {
+        self.owner_id == Some(user.get())
+    }
+
+    /// Ensure that every guild belongs to exactly one project
+    pub fn validate(&self) -> Result<()> {
+        if self.projects.is_empty() {
+            bail!("No projects are configured");
+        }
+
+        let mut guilds = HashSet::new();
+
+        for project in self.projects.iter() {
+            if project.discord_config.guilds.is_empty() {
+                bail!("Project `{}` has no guilds", project.title);
+            }
+
+            for guild in project.discord_config.guilds.iter() {
+                if !guilds.insert(*guild) {
+                    bail!("Guild {guild} belongs to multiple projects");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(content: &str, format: ConfigFormat) -> Result<T> {
+    match format {
+        ConfigFormat::Ron => parse_ron(content),
+        ConfigFormat::Toml => {
+            let de = toml::Deserializer::new(content);
+
+            serde_path_to_error::deserialize(de).map_err(into_path_error)
+        }
+        ConfigFormat::Json => {
+            let mut de = serde_json::Deserializer::from_str(content);
+            let value = serde_path_to_error::deserialize(&mut de).map_err(into_path_error)?;
+            de.end()?;
+
+            Ok(value)
+        }
+        ConfigFormat::Yaml => {
+            let de = serde_yaml::Deserializer::from_str(content);
+
+            serde_path_to_error::deserialize(de).map_err(into_path_error)
+        }
+    }
+}
+
+fn parse_ron<'de, T: Deserialize<'de>>(content: &'de str) -> Result<T> {
+    let mut de = ron::Deserializer::from_str(content)?;
+    let value = serde_path_to_error::deserialize(&mut de).map_err(|err| {
+        let path = err.path().to_string();
+
+        path_error(path, de.span_error(err.into_inner()))
+    })?;
+    de.end().map_err(|err| de.span_error(err))?;
+
+    Ok(value)
+}
+
+fn into_path_error<E: Display>(err: serde_path_to_error::Error<E>) -> Report {
+    let path = err.path().to_string();
+
+    path_error(path, err.into_inner())
+}
+
+fn path_error(path: String, err: impl Display) -> Report {
+    eyre!("Invalid value at `{path}`: {err}")
+}
+
+/// File formats that a config can be written in
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Ron,
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl ConfigFormat {
+    pub const NAMES: [&'static str; 4] = ["ron", "toml", "json", "yaml"];
+
+    /// Detect the format by the file extension, defaulting to RON
+    pub fn from_path(path: &Path) -> Self {
+        path.extension()
+            .and_then(|ext| ext.to_str())
+            .and_then(|ext| ext.parse().ok())
+            .unwrap_or(Self::Ron)
+    }
+}
+
+impl FromStr for ConfigFormat {
+    type Err = Report;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ron" => Ok(Self::Ron),
+            "toml" => Ok(Self::Toml),
+            "json" => Ok(Self::Json),
+            "yaml" | "yml" => Ok(Self::Yaml),
+            _ => bail!("Unknown config format `{s}`"),
+        }
+    }
+}
+
+impl Display for ConfigFormat {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let name = match self {
+            Self::Ron => "RON",
+            Self::Toml => "TOML",
+            Self::Json => "JSON",
+            Self::Yaml => "YAML",
+        };
+
+        f.write_str(name)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ServerConfig {
+    /// Address to listen on, e.g. `0.0.0.0:9000`
+    pub address: SocketAddr,
+}
+
+impl ServerConfig {
+    /// Read only the `server` section of the config file; secrets stay unresolved
+    pub fn load(path: &Path, format: ConfigFormat) -> Result<Option<Self>> {
+        let content = fs::read_to_string(path)
+            .wrap_err_with(|| format!("Failed to read config file at {path:?}"))?;
+
+        let section: ServerSection = deserialize(&content, format)
+            .wrap_err_with(|| format!("Failed to deserialize {format} config at {path:?}"))?;
+
+        Ok(section.server)
+    }
+}
+
+/// The part of [`Config`] that [`ServerConfig::load`] reads, ignoring all other fields
+#[derive(Deserialize)]
+#[serde(rename = "Config")]
+struct ServerSection {
+    #[serde(default)]
+    server: Option<ServerConfig>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LoggingConfig {
+    /// Directory of the log files; `None` disables logging into files
+    #[serde(default = "default_log_directory")]
+    pub directory: Option<PathBuf>,
+    /// Name of the log files, followed by the date or a number once rotated
+    #[serde(default = "default_log_file_prefix")]
+    pub file_prefix: String,
+    #[serde(default)]
+    pub rotation: LogRotation,
+    /// Amount of log files to keep, including the current one; all by default
+    #[serde(default)]
+    pub max_files: Option<usize>,
+    /// Also log to stdout
+    #[serde(default = "default_true")]
+    pub stdout: bool,
+    /// Log one JSON object per line instead of text
+    #[serde(default)]
+    pub json: bool,
+    /// Filter directives like `info,issue_bot=debug,octocrab=warn`; overridden by `RUST_LOG`
+    #[serde(default = "default_log_filter")]
+    pub filter: String,
+}
+
+impl Default for LoggingConfig {
+    fn default() -> Self {
+        Self {
+            directory: default_log_directory(),
+            file_prefix: default_log_file_prefix(),
+            rotation: LogRotation::default(),
+            max_files: None,
+            stdout: true,
+            json: false,
+            filter: default_log_filter(),
+        }
+    }
+}
+
+fn default_log_directory() -> Option<PathBuf> {
+    Some(PathBuf::from("./logs"))
+}
+
+fn default_log_file_prefix() -> String {
+    "helper.log".to_owned()
+}
+
+fn default_log_filter() -> String {
+    "info".to_owned()
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// When to start a new log file
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum LogRotation {
+    #[default]
+    Daily,
+    Hourly,
+    /// Once the file exceeds this many bytes
+    Size(u64),
+    Never,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct Project {
     pub title: String,
     pub discord_config: DiscordConfig,
     pub github_config: GithubConfig,
     pub issue_labels: IssueLabels,
+    #[serde(default)]
+    pub threads: Option<ThreadConfig>,
+    #[serde(default)]
+    pub forum: Option<ForumConfig>,
+    #[serde(default)]
+    pub reaction: Option<ReactionConfig>,
+    #[serde(default)]
+    pub reports: Option<ReportConfig>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct DiscordConfig {
-    pub token: String,
-    pub guild_id: u64,
+    pub guilds: Vec<u64>,
     pub operator_id: Vec<u64>,
+    /// Members with any of these roles have the same authority as operators
+    #[serde(default)]
+    pub authority_roles: Vec<u64>,
+    /// Channel that every state-changing action of the bot is posted into
+    #[serde(default)]
+    pub audit_channel: Option<u64>,
 }
 
 impl DiscordConfig {
-    pub fn guild_id_as_marker(&self) -> Id<GuildMarker> {
-        Id::new(self.guild_id)
+    pub fn guilds_as_marker(&self) -> impl Iterator<Item = Id<GuildMarker>> + '_ {
+        self.guilds.iter().copied().map(Id::new)
+    }
+
+    pub fn audit_channel_as_marker(&self) -> Option<Id<ChannelMarker>> {
+        self.audit_channel.map(Id::new)
     }
 
-    pub fn operator_id_as_marker(&self) -> Vec<Id<UserMarker>> {
-        self.operator_id.iter().map(|id| Id::new(*id)).collect()
+    /// Whether the user is an operator or has one of the authority roles
+    pub fn has_authority(&self, user: Id<UserMarker>, roles: &[Id<RoleMarker>]) -> bool {
+        self.operator_id.contains(&user.get())
+            || roles
+                .iter()
+                .any(|role| self.authority_roles.contains(&role.get()))
     }
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct GithubConfig {
-    pub token: String,
     pub owner: String,
     pub repositories: HashMap<String, String>,
 }
 
-#[derive(Clone, Debug, Serialize, Deserialize)]
+impl GithubConfig {
+    /// Resolve a repository by either its display name or its actual name
+    pub fn repository(&self, name: &str) -> Option<&str> {
+        self.repositories
+            .iter()
+            .find(|(display, repo)| {
+                display.eq_ignore_ascii_case(name) || repo.eq_ignore_ascii_case(name)
+            })
+            .map(|(_, repo)| repo.as_str())
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
 pub struct IssueLabels {
     pub issue_types: Vec<String>,
     pub issue_priority: Vec<String>,
 }
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ThreadConfig {
+    /// Open threads in this channel instead of on the source message
+    #[serde(default)]
+    pub channel_id: Option<u64>,
+    /// Seconds between polling github for new comments
+    #[serde(default = "default_poll_interval")]
+    pub poll_interval: u64,
+}
+
+impl ThreadConfig {
+    pub fn channel_id_as_marker(&self) -> Option<Id<ChannelMarker>> {
+        self.channel_id.map(Id::new)
+    }
+}
+
+fn default_poll_interval() -> u64 {
+    60
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ForumConfig {
+    pub channel_id: u64,
+    /// Repository that issues of forum posts are created in
+    pub repository: String,
+    /// Forum tag that turns a post into an issue when applied
+    pub accepted_tag: String,
+    /// Forum tag that is applied once the issue is closed
+    pub resolved_tag: String,
+    /// Maps forum tag names to github labels
+    #[serde(default)]
+    pub tag_labels: HashMap<String, String>,
+    /// Whether resolved posts should be locked in addition to being archived
+    #[serde(default)]
+    pub lock_resolved: bool,
+}
+
+impl ForumConfig {
+    pub fn channel_id_as_marker(&self) -> Id<ChannelMarker> {
+        Id::new(self.channel_id)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReactionConfig {
+    /// Unicode emoji, or name or id of a custom emoji
+    pub emoji: String,
+    /// Post issue builders into this channel instead of the reacted message's channel
+    #[serde(default)]
+    pub staff_channel: Option<u64>,
+}
+
+impl ReactionConfig {
+    pub fn staff_channel_as_marker(&self) -> Option<Id<ChannelMarker>> {
+        self.staff_channel.map(Id::new)
+    }
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, JsonSchema)]
+pub struct ReportConfig {
+    /// Channel in which submitted reports await moderation
+    pub queue_channel: u64,
+}
+
+impl ReportConfig {
+    pub fn queue_channel_as_marker(&self) -> Id<ChannelMarker> {
+        Id::new(self.queue_channel)
+    }
+}
+
+/// Single-project config of older versions with the tokens inside of the project.
+///
+/// Unknown fields are rejected so that settings of newer versions aren't dropped silently.
+#[derive(Deserialize)]
+#[serde(rename = "Project", deny_unknown_fields)]
+struct LegacyConfig {
+    title: String,
+    discord_config: LegacyDiscordConfig,
+    github_config: LegacyGithubConfig,
+    issue_labels: IssueLabels,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "DiscordConfig", deny_unknown_fields)]
+struct LegacyDiscordConfig {
+    token: Secret,
+    guild_id: u64,
+    operator_id: Vec<u64>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename = "GithubConfig", deny_unknown_fields)]
+struct LegacyGithubConfig {
+    token: Secret,
+    owner: String,
+    repositories: HashMap<String, String>,
+}
+
+impl From<LegacyConfig> for Config {
+    fn from(legacy: LegacyConfig) -> Self {
+        let LegacyConfig {
+            title,
+            discord_config,
+            github_config,
+            issue_labels,
+        } = legacy;
+
+        let project = Project {
+            title,
+            discord_config: DiscordConfig {
+                guilds: vec![discord_config.guild_id],
+                operator_id: discord_config.operator_id,
+                authority_roles: Vec::new(),
+                audit_channel: None,
+            },
+            github_config: GithubConfig {
+                owner: github_config.owner,
+                repositories: github_config.repositories,
+            },
+            issue_labels,
+            threads: None,
+            forum: None,
+            reaction: None,
+            reports: None,
+        };
+
+        Self {
+            discord_token: discord_config.token,
+            github_token: github_config.token,
+            owner_id: None,
+            ops_channel: None,
+            projects: vec![Arc::new(project)],
+            cooldowns: HashMap::new(),
+            global_commands: false,
+            records_path: default_records_path(),
+            reports_path: default_reports_path(),
+            audit_path: default_audit_path(),
+            server: None,
+            logging: LoggingConfig::default(),
+            legacy_format: true,
+        }
+    }
+}
@@ -1,3 +1,9 @@
 // Colors
 pub const DARK_GREEN: u32 = 0x1F8B4C;
 pub const RED: u32 = 0xE74C3C;
+
+/// Seconds until cached autocomplete suggestions are fetched again
+pub const AUTOCOMPLETE_TTL: u64 = 300;
+
+/// Seconds until the readiness check verifies the github token again
+pub const GITHUB_TOKEN_CHECK_TTL: u64 = 300;
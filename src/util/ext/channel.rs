@@ -1,6 +1,5 @@
-use std::{future::IntoFuture, slice};
+use std::slice;
 
-use twilight_http::response::ResponseFuture;
 use twilight_model::{
     channel::Message,
     guild::Permissions,
@@ -8,7 +7,10 @@ use twilight_model::{
 };
 
 use crate::{
-    core::Context,
+    core::{
+        metrics::{Api, DiscordFuture, ObserveExt},
+        Context,
+    },
     util::{
         builder::{EmbedBuilder, MessageBuilder},
         constants::RED,
@@ -22,13 +24,13 @@ pub trait ChannelExt {
         ctx: &Context,
         builder: &MessageBuilder<'_>,
         permissions: Option<Permissions>,
-    ) -> ResponseFuture<Message>;
+    ) -> DiscordFuture<Message>;
 
     /// Create a message inside a red embed
-    fn error(&self, ctx: &Context, content: impl Into<String>) -> ResponseFuture<Message>;
+    fn error(&self, ctx: &Context, content: impl Into<String>) -> DiscordFuture<Message>;
 
     /// Create a message without embed; only content
-    fn plain_message(&self, ctx: &Context, content: &str) -> ResponseFuture<Message>;
+    fn plain_message(&self, ctx: &Context, content: &str) -> DiscordFuture<Message>;
 }
 
 impl ChannelExt for Id<ChannelMarker> {
@@ -37,7 +39,7 @@ impl ChannelExt for Id<ChannelMarker> {
         ctx: &Context,
         builder: &MessageBuilder<'_>,
         permissions: Option<Permissions>,
-    ) -> ResponseFuture<Message> {
+    ) -> DiscordFuture<Message> {
         let mut req = ctx.http.create_message(*self);
 
         if let Some(ref content) = builder.content {
@@ -57,27 +59,29 @@ impl ChannelExt for Id<ChannelMarker> {
                 permissions.contains(Permissions::ATTACH_FILES)
             })
         }) {
-            Some(attachment) => req.attachments(slice::from_ref(attachment)).into_future(),
-            None => req.into_future(),
+            Some(attachment) => req
+                .attachments(slice::from_ref(attachment))
+                .observe(Api::Discord, "create_message"),
+            None => req.observe(Api::Discord, "create_message"),
         }
     }
 
     #[inline]
-    fn error(&self, ctx: &Context, content: impl Into<String>) -> ResponseFuture<Message> {
+    fn error(&self, ctx: &Context, content: impl Into<String>) -> DiscordFuture<Message> {
         let embed = EmbedBuilder::new().color(RED).description(content).build();
 
         ctx.http
             .create_message(*self)
             .embeds(&[embed])
-            .into_future()
+            .observe(Api::Discord, "create_message")
     }
 
     #[inline]
-    fn plain_message(&self, ctx: &Context, content: &str) -> ResponseFuture<Message> {
+    fn plain_message(&self, ctx: &Context, content: &str) -> DiscordFuture<Message> {
         ctx.http
             .create_message(*self)
             .content(content)
-            .into_future()
+            .observe(Api::Discord, "create_message")
     }
 }
 
@@ -88,17 +92,17 @@ impl ChannelExt for Message {
         ctx: &Context,
         builder: &MessageBuilder<'_>,
         permissions: Option<Permissions>,
-    ) -> ResponseFuture<Message> {
+    ) -> DiscordFuture<Message> {
         self.channel_id.create_message(ctx, builder, permissions)
     }
 
     #[inline]
-    fn error(&self, ctx: &Context, content: impl Into<String>) -> ResponseFuture<Message> {
+    fn error(&self, ctx: &Context, content: impl Into<String>) -> DiscordFuture<Message> {
         self.channel_id.error(ctx, content)
     }
 
     #[inline]
-    fn plain_message(&self, ctx: &Context, content: &str) -> ResponseFuture<Message> {
+    fn plain_message(&self, ctx: &Context, content: &str) -> DiscordFuture<Message> {
         self.channel_id.plain_message(ctx, content)
     }
 }
@@ -1,44 +1,49 @@
-use std::{borrow::Cow, future::IntoFuture};
+use std::borrow::Cow;
 
-use twilight_http::response::{marker::EmptyBody, ResponseFuture};
+use twilight_http::response::marker::EmptyBody;
 use twilight_model::{
-    channel::Message,
+    channel::{message::MessageFlags, Message},
     guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
 
 use super::MessageExt;
 use crate::{
-    core::Context,
+    core::{
+        metrics::{Api, DiscordFuture, ObserveExt},
+        Context,
+    },
     util::{
-        builder::{MessageBuilder, ModalBuilder},
+        builder::{EmbedBuilder, MessageBuilder, ModalBuilder},
+        constants::RED,
         interaction::InteractionComponent,
     },
 };
 
 pub trait ComponentExt {
     /// Ackowledge the component and respond immediatly by updating the message.
-    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody>;
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> DiscordFuture<EmptyBody>;
 
     /// Ackownledge the component but don't respond yet.
-    fn defer(&self, ctx: &Context) -> ResponseFuture<EmptyBody>;
+    fn defer(&self, ctx: &Context) -> DiscordFuture<EmptyBody>;
 
     /// After having already ackowledged the component either via
     /// [`ComponentExt::callback`] or [`ComponentExt::defer`],
     /// use this to update the message.
-    fn update(
-        &self,
-        ctx: &Context,
-        builder: &MessageBuilder<'_>,
-    ) -> Option<ResponseFuture<Message>>;
+    fn update(&self, ctx: &Context, builder: &MessageBuilder<'_>)
+        -> Option<DiscordFuture<Message>>;
 
     /// Acknowledge a component by responding with a modal.
-    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> ResponseFuture<EmptyBody>;
+    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> DiscordFuture<EmptyBody>;
+
+    /// Respond to the component with some content in a red embed that only the author can see.
+    fn error_callback(&self, ctx: &Context, content: impl Into<String>)
+        -> DiscordFuture<EmptyBody>;
 }
 
 impl ComponentExt for InteractionComponent {
     #[inline]
-    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> DiscordFuture<EmptyBody> {
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -63,11 +68,11 @@ impl ComponentExt for InteractionComponent {
 
         ctx.interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .observe(Api::Discord, "create_response")
     }
 
     #[inline]
-    fn defer(&self, ctx: &Context) -> ResponseFuture<EmptyBody> {
+    fn defer(&self, ctx: &Context) -> DiscordFuture<EmptyBody> {
         let response = InteractionResponse {
             kind: InteractionResponseType::DeferredUpdateMessage,
             data: None,
@@ -75,7 +80,7 @@ impl ComponentExt for InteractionComponent {
 
         ctx.interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .observe(Api::Discord, "create_response")
     }
 
     #[inline]
@@ -83,12 +88,12 @@ impl ComponentExt for InteractionComponent {
         &self,
         ctx: &Context,
         builder: &MessageBuilder<'_>,
-    ) -> Option<ResponseFuture<Message>> {
+    ) -> Option<DiscordFuture<Message>> {
         self.message.update(ctx, builder, self.permissions)
     }
 
     #[inline]
-    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> ResponseFuture<EmptyBody> {
+    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> DiscordFuture<EmptyBody> {
         let response = InteractionResponse {
             kind: InteractionResponseType::Modal,
             data: Some(modal.build()),
@@ -96,6 +101,30 @@ impl ComponentExt for InteractionComponent {
 
         ctx.interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .observe(Api::Discord, "create_response")
+    }
+
+    #[inline]
+    fn error_callback(
+        &self,
+        ctx: &Context,
+        content: impl Into<String>,
+    ) -> DiscordFuture<EmptyBody> {
+        let embed = EmbedBuilder::new().description(content).color(RED).build();
+
+        let data = InteractionResponseData {
+            embeds: Some(vec![embed]),
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        };
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        };
+
+        ctx.interaction()
+            .create_response(self.id, &self.token, &response)
+            .observe(Api::Discord, "create_response")
     }
 }
@@ -1,6 +1,6 @@
-use std::{borrow::Cow, future::IntoFuture, mem, slice};
+use std::{borrow::Cow, mem, slice};
 
-use twilight_http::response::{marker::EmptyBody, ResponseFuture};
+use twilight_http::response::marker::EmptyBody;
 use twilight_interactions::command::CommandInputData;
 use twilight_model::{
     application::command::CommandOptionChoice,
@@ -10,9 +10,12 @@ use twilight_model::{
 };
 
 use crate::{
-    core::Context,
+    core::{
+        metrics::{Api, DiscordFuture, ObserveExt},
+        Context,
+    },
     util::{
-        builder::{EmbedBuilder, MessageBuilder},
+        builder::{EmbedBuilder, MessageBuilder, ModalBuilder},
         constants::RED,
         interaction::InteractionCommand,
     },
@@ -28,38 +31,41 @@ pub trait InteractionCommandExt {
         ctx: &Context,
         builder: MessageBuilder<'_>,
         ephemeral: bool,
-    ) -> ResponseFuture<EmptyBody>;
+    ) -> DiscordFuture<EmptyBody>;
 
     /// Ackownledge the command but don't respond yet.
     ///
     /// Must use [`ApplicationCommandExt::update`] afterwards!
-    fn defer(&self, ctx: &Context, ephemeral: bool) -> ResponseFuture<EmptyBody>;
+    fn defer(&self, ctx: &Context, ephemeral: bool) -> DiscordFuture<EmptyBody>;
 
     /// After having already ackowledged the command either via
     /// [`ApplicationCommandExt::callback`] or [`ApplicationCommandExt::defer`],
     /// use this to update the response.
-    fn update(&self, ctx: &Context, builder: &MessageBuilder<'_>) -> ResponseFuture<Message>;
+    fn update(&self, ctx: &Context, builder: &MessageBuilder<'_>) -> DiscordFuture<Message>;
 
     /// Update a command to some content in a red embed.
     ///
     /// Be sure the command was deferred beforehand.
-    fn error(&self, ctx: &Context, content: impl Into<String>) -> ResponseFuture<Message>;
+    fn error(&self, ctx: &Context, content: impl Into<String>) -> DiscordFuture<Message>;
 
-    /// Respond to a command with some content in a red embed.
+    /// Respond to a command with some content in a red embed that only the author can see.
     ///
     /// Be sure the command was **not** deferred beforehand.
-    fn error_callback(
-        &self,
-        ctx: &Context,
-        content: impl Into<String>,
-    ) -> ResponseFuture<EmptyBody>;
+    fn error_callback(&self, ctx: &Context, content: impl Into<String>)
+        -> DiscordFuture<EmptyBody>;
+
+    /// Send a red embed that only the author can see after the command was acknowledged.
+    fn error_followup(&self, ctx: &Context, content: impl Into<String>) -> DiscordFuture<Message>;
+
+    /// Acknowledge the command by responding with a modal.
+    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> DiscordFuture<EmptyBody>;
 
     /// Callback to an autocomplete action.
     fn autocomplete(
         &self,
         ctx: &Context,
         choices: Vec<CommandOptionChoice>,
-    ) -> ResponseFuture<EmptyBody>;
+    ) -> DiscordFuture<EmptyBody>;
 }
 
 impl InteractionCommandExt for InteractionCommand {
@@ -77,7 +83,7 @@ impl InteractionCommandExt for InteractionCommand {
         ctx: &Context,
         builder: MessageBuilder<'_>,
         ephemeral: bool,
-    ) -> ResponseFuture<EmptyBody> {
+    ) -> DiscordFuture<EmptyBody> {
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -103,11 +109,11 @@ impl InteractionCommandExt for InteractionCommand {
 
         ctx.interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .observe(Api::Discord, "create_response")
     }
 
     #[inline]
-    fn defer(&self, ctx: &Context, ephemeral: bool) -> ResponseFuture<EmptyBody> {
+    fn defer(&self, ctx: &Context, ephemeral: bool) -> DiscordFuture<EmptyBody> {
         let data = InteractionResponseData {
             flags: ephemeral.then_some(MessageFlags::EPHEMERAL),
             ..Default::default()
@@ -120,7 +126,7 @@ impl InteractionCommandExt for InteractionCommand {
 
         ctx.interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .observe(Api::Discord, "create_response")
     }
 
     #[inline]
@@ -128,7 +134,7 @@ impl InteractionCommandExt for InteractionCommand {
         &'l self,
         ctx: &'l Context,
         builder: &'l MessageBuilder<'l>,
-    ) -> ResponseFuture<Message> {
+    ) -> DiscordFuture<Message> {
         let client = ctx.interaction();
 
         let mut req = client.update_response(&self.token);
@@ -153,17 +159,17 @@ impl InteractionCommandExt for InteractionCommand {
             req = req.attachments(slice::from_ref(attachment));
         }
 
-        req.into_future()
+        req.observe(Api::Discord, "update_response")
     }
 
     #[inline]
-    fn error(&self, ctx: &Context, content: impl Into<String>) -> ResponseFuture<Message> {
+    fn error(&self, ctx: &Context, content: impl Into<String>) -> DiscordFuture<Message> {
         let embed = EmbedBuilder::new().description(content).color(RED).build();
 
         ctx.interaction()
             .update_response(&self.token)
             .embeds(Some(&[embed]))
-            .into_future()
+            .observe(Api::Discord, "update_response")
     }
 
     #[inline]
@@ -171,11 +177,12 @@ impl InteractionCommandExt for InteractionCommand {
         &self,
         ctx: &Context,
         content: impl Into<String>,
-    ) -> ResponseFuture<EmptyBody> {
+    ) -> DiscordFuture<EmptyBody> {
         let embed = EmbedBuilder::new().description(content).color(RED).build();
 
         let data = InteractionResponseData {
             embeds: Some(vec![embed]),
+            flags: Some(MessageFlags::EPHEMERAL),
             ..Default::default()
         };
 
@@ -186,7 +193,30 @@ impl InteractionCommandExt for InteractionCommand {
 
         ctx.interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .observe(Api::Discord, "create_response")
+    }
+
+    #[inline]
+    fn error_followup(&self, ctx: &Context, content: impl Into<String>) -> DiscordFuture<Message> {
+        let embed = EmbedBuilder::new().description(content).color(RED).build();
+
+        ctx.interaction()
+            .create_followup(&self.token)
+            .embeds(&[embed])
+            .flags(MessageFlags::EPHEMERAL)
+            .observe(Api::Discord, "create_followup")
+    }
+
+    #[inline]
+    fn modal(&self, ctx: &Context, modal: ModalBuilder) -> DiscordFuture<EmptyBody> {
+        let response = InteractionResponse {
+            kind: InteractionResponseType::Modal,
+            data: Some(modal.build()),
+        };
+
+        ctx.interaction()
+            .create_response(self.id, &self.token, &response)
+            .observe(Api::Discord, "create_response")
     }
 
     #[inline]
@@ -194,7 +224,7 @@ impl InteractionCommandExt for InteractionCommand {
         &self,
         ctx: &Context,
         choices: Vec<CommandOptionChoice>,
-    ) -> ResponseFuture<EmptyBody> {
+    ) -> DiscordFuture<EmptyBody> {
         let data = InteractionResponseData {
             choices: Some(choices),
             ..Default::default()
@@ -207,6 +237,6 @@ impl InteractionCommandExt for InteractionCommand {
 
         ctx.interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .observe(Api::Discord, "create_response")
     }
 }
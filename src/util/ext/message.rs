@@ -1,6 +1,6 @@
-use std::{future::IntoFuture, slice};
+use std::slice;
 
-use twilight_http::response::{marker::EmptyBody, ResponseFuture};
+use twilight_http::response::marker::EmptyBody;
 use twilight_model::{
     channel::Message,
     guild::Permissions,
@@ -10,7 +10,13 @@ use twilight_model::{
     },
 };
 
-use crate::{core::Context, util::builder::MessageBuilder};
+use crate::{
+    core::{
+        metrics::{Api, DiscordFuture, ObserveExt},
+        Context,
+    },
+    util::builder::MessageBuilder,
+};
 
 pub trait MessageExt {
     fn update(
@@ -18,9 +24,9 @@ pub trait MessageExt {
         ctx: &Context,
         builder: &MessageBuilder<'_>,
         permissions: Option<Permissions>,
-    ) -> Option<ResponseFuture<Message>>;
+    ) -> Option<DiscordFuture<Message>>;
 
-    fn delete(&self, ctx: &Context) -> ResponseFuture<EmptyBody>;
+    fn delete(&self, ctx: &Context) -> DiscordFuture<EmptyBody>;
 }
 
 impl MessageExt for (Id<MessageMarker>, Id<ChannelMarker>) {
@@ -29,7 +35,7 @@ impl MessageExt for (Id<MessageMarker>, Id<ChannelMarker>) {
         ctx: &Context,
         builder: &MessageBuilder<'_>,
         permissions: Option<Permissions>,
-    ) -> Option<ResponseFuture<Message>> {
+    ) -> Option<DiscordFuture<Message>> {
         let can_view_channel = permissions.map_or(true, |permissions| {
             permissions.contains(Permissions::VIEW_CHANNEL)
         });
@@ -53,12 +59,14 @@ impl MessageExt for (Id<MessageMarker>, Id<ChannelMarker>) {
             req = req.components(Some(components));
         }
 
-        Some(req.into_future())
+        Some(req.observe(Api::Discord, "update_message"))
     }
 
     #[inline]
-    fn delete<'l>(&'l self, ctx: &'l Context) -> ResponseFuture<EmptyBody> {
-        ctx.http.delete_message(self.1, self.0).into_future()
+    fn delete<'l>(&'l self, ctx: &'l Context) -> DiscordFuture<EmptyBody> {
+        ctx.http
+            .delete_message(self.1, self.0)
+            .observe(Api::Discord, "delete_message")
     }
 }
 
@@ -69,12 +77,12 @@ impl MessageExt for Message {
         ctx: &Context,
         builder: &MessageBuilder<'_>,
         permissions: Option<Permissions>,
-    ) -> Option<ResponseFuture<Message>> {
+    ) -> Option<DiscordFuture<Message>> {
         (self.id, self.channel_id).update(ctx, builder, permissions)
     }
 
     #[inline]
-    fn delete(&self, ctx: &Context) -> ResponseFuture<EmptyBody> {
+    fn delete(&self, ctx: &Context) -> DiscordFuture<EmptyBody> {
         (self.id, self.channel_id).delete(ctx)
     }
 }
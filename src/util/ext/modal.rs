@@ -1,40 +1,56 @@
-use std::future::IntoFuture;
+use std::borrow::Cow;
 
-use twilight_http::response::{marker::EmptyBody, ResponseFuture};
+use twilight_http::response::marker::EmptyBody;
 use twilight_model::{
-    channel::Message,
+    channel::{message::MessageFlags, Message},
     guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
 
 use super::MessageExt;
 use crate::{
-    core::Context,
-    util::{builder::MessageBuilder, interaction::InteractionModal},
+    core::{
+        metrics::{Api, DiscordFuture, ObserveExt},
+        Context,
+    },
+    util::{
+        builder::{EmbedBuilder, MessageBuilder},
+        constants::RED,
+        interaction::InteractionModal,
+    },
 };
 
 pub trait ModalExt {
     /// Ackowledge the modal and respond immediatly by updating the message.
-    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody>;
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> DiscordFuture<EmptyBody>;
 
     /// Ackownledge the modal but don't respond yet.
-    fn defer(&self, ctx: &Context) -> ResponseFuture<EmptyBody>;
+    fn defer(&self, ctx: &Context) -> DiscordFuture<EmptyBody>;
 
     /// After having already ackowledged the modal either via
     /// [`ModalExt::callback`] or [`ModalExt::defer`],
     /// use this to update the message.
     ///
     /// Note: Can only be used if `ModalSubmitInteraction::message` is `Some`.
-    fn update(
+    fn update(&self, ctx: &Context, builder: &MessageBuilder<'_>)
+        -> Option<DiscordFuture<Message>>;
+
+    /// Acknowledge a modal that was opened by a command by responding with a new message.
+    fn reply(
         &self,
         ctx: &Context,
-        builder: &MessageBuilder<'_>,
-    ) -> Option<ResponseFuture<Message>>;
+        builder: MessageBuilder<'_>,
+        ephemeral: bool,
+    ) -> DiscordFuture<EmptyBody>;
+
+    /// Respond to the modal with some content in a red embed that only the author can see.
+    fn error_callback(&self, ctx: &Context, content: impl Into<String>)
+        -> DiscordFuture<EmptyBody>;
 }
 
 impl ModalExt for InteractionModal {
     #[inline]
-    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+    fn callback(&self, ctx: &Context, builder: MessageBuilder<'_>) -> DiscordFuture<EmptyBody> {
         let attachments = builder
             .attachment
             .filter(|_| {
@@ -58,11 +74,11 @@ impl ModalExt for InteractionModal {
 
         ctx.interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .observe(Api::Discord, "create_response")
     }
 
     #[inline]
-    fn defer(&self, ctx: &Context) -> ResponseFuture<EmptyBody> {
+    fn defer(&self, ctx: &Context) -> DiscordFuture<EmptyBody> {
         let response = InteractionResponse {
             kind: InteractionResponseType::DeferredUpdateMessage,
             data: None,
@@ -70,7 +86,7 @@ impl ModalExt for InteractionModal {
 
         ctx.interaction()
             .create_response(self.id, &self.token, &response)
-            .into_future()
+            .observe(Api::Discord, "create_response")
     }
 
     #[inline]
@@ -78,10 +94,59 @@ impl ModalExt for InteractionModal {
         &self,
         ctx: &Context,
         builder: &MessageBuilder<'_>,
-    ) -> Option<ResponseFuture<Message>> {
+    ) -> Option<DiscordFuture<Message>> {
         self.message
             .as_ref()
             .expect("no message in modal")
             .update(ctx, builder, self.permissions)
     }
+
+    #[inline]
+    fn reply(
+        &self,
+        ctx: &Context,
+        builder: MessageBuilder<'_>,
+        ephemeral: bool,
+    ) -> DiscordFuture<EmptyBody> {
+        let data = InteractionResponseData {
+            components: builder.components,
+            embeds: builder.embed.map(|e| vec![e]),
+            content: builder.content.map(Cow::into_owned),
+            flags: ephemeral.then_some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        };
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        };
+
+        ctx.interaction()
+            .create_response(self.id, &self.token, &response)
+            .observe(Api::Discord, "create_response")
+    }
+
+    #[inline]
+    fn error_callback(
+        &self,
+        ctx: &Context,
+        content: impl Into<String>,
+    ) -> DiscordFuture<EmptyBody> {
+        let embed = EmbedBuilder::new().description(content).color(RED).build();
+
+        let data = InteractionResponseData {
+            embeds: Some(vec![embed]),
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        };
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        };
+
+        ctx.interaction()
+            .create_response(self.id, &self.token, &response)
+            .observe(Api::Discord, "create_response")
+    }
 }
@@ -0,0 +1,385 @@
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
+
+use eyre::{Result, WrapErr};
+use octocrab::{
+    models::{
+        issues::{Comment, Issue, IssueStateReason},
+        IssueState,
+    },
+    params::LockReason,
+    Octocrab,
+};
+use serde::Serialize;
+use time::OffsetDateTime;
+
+use crate::{
+    core::{
+        audit::AuditAction,
+        metrics::{Api, ObserveExt},
+        Context,
+    },
+    util::{builder::EmbedBuilder, config::Project, constants::RED, locale::t},
+};
+
+/// Reference to an issue of a repository of the configured owner
+#[derive(Clone)]
+pub struct IssueRef {
+    pub owner: String,
+    pub repo: String,
+    pub number: u64,
+}
+
+impl IssueRef {
+    /// Resolve the repository by either its display name or its actual name.
+    ///
+    /// Returns `None` if the repository is not configured.
+    pub fn resolve(project: &Project, repo: &str, number: u64) -> Option<Self> {
+        let github = &project.github_config;
+
+        github.repository(repo).map(|repo| Self {
+            owner: github.owner.clone(),
+            repo: repo.to_owned(),
+            number,
+        })
+    }
+
+    pub fn url(&self) -> String {
+        let Self {
+            owner,
+            repo,
+            number,
+        } = self;
+
+        format!("https://github.com/{owner}/{repo}/issues/{number}")
+    }
+
+    /// Comment on the issue on behalf of a discord user
+    pub async fn comment(&self, ctx: &Context, content: &str, author: &str) -> Result<Comment> {
+        let body = format!("{content}\n\n*Posted from Discord by @{author}*");
+
+        ctx.github
+            .issues(&self.owner, &self.repo)
+            .create_comment(self.number, body)
+            .observe(Api::Github, "issues.create_comment")
+            .await
+            .wrap_err("Failed to create comment")
+    }
+
+    /// Apply the action on github and return an embed describing the outcome
+    pub async fn apply(
+        &self,
+        ctx: &Context,
+        action: &IssueAction,
+        locale: &str,
+    ) -> Result<EmbedBuilder> {
+        let handler = ctx.github.issues(&self.owner, &self.repo);
+
+        let description = match action {
+            IssueAction::Close(reason) => {
+                handler
+                    .update(self.number)
+                    .state(IssueState::Closed)
+                    .state_reason(reason.clone())
+                    .send()
+                    .observe(Api::Github, "issues.update")
+                    .await
+                    .wrap_err("Failed to close issue")?;
+
+                match reason {
+                    IssueStateReason::NotPlanned => t!(locale, "issue-closed-not-planned"),
+                    _ => t!(locale, "issue-closed-completed"),
+                }
+            }
+            IssueAction::Reopen => {
+                handler
+                    .update(self.number)
+                    .state(IssueState::Open)
+                    .send()
+                    .observe(Api::Github, "issues.update")
+                    .await
+                    .wrap_err("Failed to reopen issue")?;
+
+                t!(locale, "issue-reopened")
+            }
+            IssueAction::AddLabels(labels) => {
+                handler
+                    .add_labels(self.number, labels)
+                    .observe(Api::Github, "issues.add_labels")
+                    .await
+                    .wrap_err("Failed to add labels")?;
+
+                t!(locale, "issue-labels-added", labels = labels.join(", "))
+            }
+            IssueAction::RemoveLabel(label) => {
+                handler
+                    .remove_label(self.number, label)
+                    .observe(Api::Github, "issues.remove_label")
+                    .await
+                    .wrap_err("Failed to remove label")?;
+
+                t!(locale, "issue-label-removed", label = label.as_str())
+            }
+            IssueAction::Assign(assignees) => {
+                let assignees: Vec<_> = assignees.iter().map(String::as_str).collect();
+
+                handler
+                    .add_assignees(self.number, &assignees)
+                    .observe(Api::Github, "issues.add_assignees")
+                    .await
+                    .wrap_err("Failed to add assignees")?;
+
+                t!(locale, "issue-assigned", users = assignees.join(", "))
+            }
+            IssueAction::Unassign(assignees) => {
+                #[derive(Serialize)]
+                struct Assignees<'a> {
+                    assignees: &'a [String],
+                }
+
+                let route = format!(
+                    "/repos/{}/{}/issues/{}/assignees",
+                    self.owner, self.repo, self.number
+                );
+
+                let body = Assignees { assignees };
+
+                ctx.github
+                    .delete::<Issue, _, _>(route, Some(&body))
+                    .observe(Api::Github, "issues.remove_assignees")
+                    .await
+                    .wrap_err("Failed to remove assignees")?;
+
+                t!(locale, "issue-unassigned", users = assignees.join(", "))
+            }
+            IssueAction::Lock(reason) => {
+                handler
+                    .lock(self.number, *reason)
+                    .observe(Api::Github, "issues.lock")
+                    .await
+                    .wrap_err("Failed to lock issue")?;
+
+                t!(locale, "issue-locked")
+            }
+        };
+
+        let embed = EmbedBuilder::new()
+            .title(self.to_string())
+            .url(self.url())
+            .description(description);
+
+        Ok(embed)
+    }
+}
+
+impl Display for IssueRef {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let Self {
+            owner,
+            repo,
+            number,
+        } = self;
+
+        write!(f, "{owner}/{repo}#{number}")
+    }
+}
+
+pub enum IssueAction {
+    Close(IssueStateReason),
+    Reopen,
+    AddLabels(Vec<String>),
+    RemoveLabel(String),
+    Assign(Vec<String>),
+    Unassign(Vec<String>),
+    Lock(Option<LockReason>),
+}
+
+impl IssueAction {
+    /// Whether the action should be confirmed before applying it
+    pub fn is_destructive(&self) -> bool {
+        matches!(
+            self,
+            Self::Close(_) | Self::RemoveLabel(_) | Self::Unassign(_) | Self::Lock(_)
+        )
+    }
+
+    /// Embed asking to confirm the action
+    pub fn confirmation(&self, issue: &IssueRef, locale: &str) -> EmbedBuilder {
+        let issue = format!("[{issue}]({})", issue.url());
+
+        let description = match self {
+            Self::Close(IssueStateReason::NotPlanned) => {
+                t!(locale, "issue-confirm-close-not-planned", issue = issue)
+            }
+            Self::Close(_) => t!(locale, "issue-confirm-close-completed", issue = issue),
+            Self::Reopen => t!(locale, "issue-confirm-reopen", issue = issue),
+            Self::AddLabels(labels) => t!(
+                locale,
+                "issue-confirm-add-labels",
+                labels = labels.join(", "),
+                issue = issue,
+            ),
+            Self::RemoveLabel(label) => t!(
+                locale,
+                "issue-confirm-remove-label",
+                label = label.as_str(),
+                issue = issue,
+            ),
+            Self::Assign(assignees) => t!(
+                locale,
+                "issue-confirm-assign",
+                users = assignees.join(", "),
+                issue = issue,
+            ),
+            Self::Unassign(assignees) => t!(
+                locale,
+                "issue-confirm-unassign",
+                users = assignees.join(", "),
+                issue = issue,
+            ),
+            Self::Lock(_) => t!(locale, "issue-confirm-lock", issue = issue),
+        };
+
+        EmbedBuilder::new()
+            .title(t!(locale, "issue-confirm-title"))
+            .color(RED)
+            .description(description)
+    }
+
+    /// Audit log entry of the action after it was applied to the issue
+    pub fn audit(&self, issue: &IssueRef) -> AuditAction {
+        let issue = issue.url();
+
+        match self {
+            Self::Close(reason) => {
+                let reason = match reason {
+                    IssueStateReason::NotPlanned => "not planned",
+                    _ => "completed",
+                };
+
+                AuditAction::IssueClosed {
+                    issue,
+                    reason: reason.to_owned(),
+                }
+            }
+            Self::Reopen => AuditAction::IssueReopened { issue },
+            Self::AddLabels(labels) => AuditAction::LabelsAdded {
+                issue,
+                labels: labels.clone(),
+            },
+            Self::RemoveLabel(label) => AuditAction::LabelRemoved {
+                issue,
+                label: label.clone(),
+            },
+            Self::Assign(assignees) => AuditAction::AssigneesAdded {
+                issue,
+                assignees: assignees.clone(),
+            },
+            Self::Unassign(assignees) => AuditAction::AssigneesRemoved {
+                issue,
+                assignees: assignees.clone(),
+            },
+            Self::Lock(reason) => {
+                let reason = reason.map(|reason| match reason {
+                    LockReason::OffTopic => "off-topic".to_owned(),
+                    LockReason::TooHeated => "too heated".to_owned(),
+                    LockReason::Resolved => "resolved".to_owned(),
+                    LockReason::Spam => "spam".to_owned(),
+                    other => format!("{other:?}"),
+                });
+
+                AuditAction::IssueLocked { issue, reason }
+            }
+        }
+    }
+
+    /// The action as it is phrased in front of the issue, e.g. `close as completed`.
+    ///
+    /// Not localized since it only describes drafts in the audit log.
+    pub fn phrase(&self) -> String {
+        match self {
+            Self::Close(IssueStateReason::NotPlanned) => "close as not planned".to_owned(),
+            Self::Close(_) => "close as completed".to_owned(),
+            Self::Reopen => "reopen".to_owned(),
+            Self::AddLabels(labels) => format!("add the labels `{}` to", labels.join(", ")),
+            Self::RemoveLabel(label) => format!("remove the label `{label}` from"),
+            Self::Assign(assignees) => format!("assign `{}` to", assignees.join(", ")),
+            Self::Unassign(assignees) => format!("unassign `{}` from", assignees.join(", ")),
+            Self::Lock(_) => "lock".to_owned(),
+        }
+    }
+}
+
+/// Body of an issue that was created from a discord message
+pub fn issue_body(
+    content: &str,
+    attachments: &[String],
+    author: Option<&str>,
+    source_url: &str,
+) -> String {
+    let mut body = String::with_capacity(content.len() + 32);
+
+    if !content.is_empty() {
+        for line in content.lines() {
+            let _ = writeln!(body, "> {line}");
+        }
+
+        body.push('\n');
+    }
+
+    if !attachments.is_empty() {
+        body.push_str("Attachments:\n");
+
+        for url in attachments.iter() {
+            let _ = writeln!(body, "![attachment]({url})");
+        }
+    }
+
+    let _ = match author {
+        Some(author) => write!(body, "[Original message by @{author}]({source_url})"),
+        None => write!(body, "[Original message]({source_url})"),
+    };
+
+    body
+}
+
+/// Names of all labels of the repository
+pub async fn repo_labels(
+    github: &Octocrab,
+    owner: &str,
+    repo: &str,
+) -> octocrab::Result<Vec<String>> {
+    let mut labels = Vec::new();
+    let mut page = 1_u32;
+
+    loop {
+        let response = github
+            .issues(owner, repo)
+            .list_labels_for_repo()
+            .per_page(100)
+            .page(page)
+            .send()
+            .observe(Api::Github, "issues.list_labels")
+            .await?;
+
+        let done = response.next.is_none();
+        labels.extend(response.items.into_iter().map(|label| label.name));
+
+        if done {
+            return Ok(labels);
+        }
+
+        page += 1;
+    }
+}
+
+/// Convert a timestamp of the github API, given in unix seconds e.g. `issue.created_at.timestamp()`
+pub fn github_time(unix: i64) -> OffsetDateTime {
+    OffsetDateTime::from_unix_timestamp(unix).unwrap_or(OffsetDateTime::UNIX_EPOCH)
+}
+
+/// Quote the value of a search qualifier so that spaces can't add further qualifiers.
+///
+/// Returns `None` if the value contains a quote since the search can't escape them.
+pub fn search_value(value: &str) -> Option<String> {
+    (!value.contains('"')).then(|| format!("\"{value}\""))
+}
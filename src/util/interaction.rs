@@ -15,13 +15,14 @@ use twilight_model::{
 
 use super::ext::Authored;
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct InteractionCommand {
     pub permissions: Option<Permissions>,
     pub channel_id: Id<ChannelMarker>,
     pub data: Box<CommandData>,
     pub guild_id: Option<Id<GuildMarker>>,
     pub id: Id<InteractionMarker>,
+    pub locale: String,
     pub member: Option<PartialMember>,
     pub token: String,
     pub user: Option<User>,
@@ -34,19 +35,21 @@ pub struct InteractionComponent {
     pub data: Box<MessageComponentInteractionData>,
     pub guild_id: Option<Id<GuildMarker>>,
     pub id: Id<InteractionMarker>,
+    pub locale: String,
     pub member: Option<PartialMember>,
     pub message: Message,
     pub token: String,
     pub user: Option<User>,
 }
 
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub struct InteractionModal {
     pub permissions: Option<Permissions>,
     pub channel_id: Id<ChannelMarker>,
     pub data: ModalInteractionData,
     pub guild_id: Option<Id<GuildMarker>>,
     pub id: Id<InteractionMarker>,
+    pub locale: String,
     pub member: Option<PartialMember>,
     pub message: Option<Message>,
     pub token: String,
@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use eyre::{Result, WrapErr};
+use fluent_bundle::{concurrent::FluentBundle, FluentArgs, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+/// Locale of the code's own strings; every other locale falls back to it
+pub const DEFAULT_LOCALE: &str = "en-US";
+
+/// Default of stored locales that predate them
+pub fn default_locale() -> String {
+    DEFAULT_LOCALE.to_owned()
+}
+
+/// Fluent files of every supported locale, named by their discord locale code
+const CATALOG: &[(&str, &str)] = &[
+    ("en-US", include_str!("../../locales/en-US.ftl")),
+    ("de", include_str!("../../locales/de.ftl")),
+];
+
+lazy_static::lazy_static! {
+    pub static ref LOCALES: Locales = match Locales::new() {
+        Ok(locales) => locales,
+        Err(err) => panic!("Invalid localization catalog: {err:?}"),
+    };
+}
+
+/// Localized strings, keyed by discord locale
+pub struct Locales {
+    bundles: HashMap<&'static str, FluentBundle<FluentResource>>,
+}
+
+impl Locales {
+    fn new() -> Result<Self> {
+        let mut bundles = HashMap::with_capacity(CATALOG.len());
+
+        for &(locale, source) in CATALOG {
+            let langid: LanguageIdentifier = locale
+                .parse()
+                .wrap_err_with(|| format!("Invalid locale `{locale}`"))?;
+
+            let resource = FluentResource::try_new(source.to_owned()).map_err(|(_, errors)| {
+                eyre!("Failed to parse catalog of `{locale}`: {errors:?}")
+            })?;
+
+            let mut bundle = FluentBundle::new_concurrent(vec![langid]);
+
+            // Discord would render the unicode isolation marks around arguments
+            bundle.set_use_isolating(false);
+
+            bundle
+                .add_resource(resource)
+                .map_err(|errors| eyre!("Duplicate messages in `{locale}`: {errors:?}"))?;
+
+            bundles.insert(locale, bundle);
+        }
+
+        Ok(Self { bundles })
+    }
+
+    /// The message in the given locale.
+    ///
+    /// Falls back to the language without region, e.g. `de` for `de-AT`, then to the
+    /// default locale, and finally to the key itself.
+    pub fn get(&self, locale: &str, key: &str, args: Option<&FluentArgs<'_>>) -> String {
+        let language = locale.split('-').next().unwrap_or(locale);
+
+        [locale, language, DEFAULT_LOCALE]
+            .into_iter()
+            .find_map(|locale| self.format(locale, key, args))
+            .unwrap_or_else(|| {
+                warn!(key, "Missing localization");
+
+                key.to_owned()
+            })
+    }
+
+    /// The message in every locale that provides it, except for the default one.
+    ///
+    /// Used for the localizations of command names and descriptions.
+    pub fn localizations(&self, key: &str) -> Option<HashMap<String, String>> {
+        let localizations: HashMap<_, _> = self
+            .bundles
+            .keys()
+            .filter(|&&locale| locale != DEFAULT_LOCALE)
+            .filter_map(|&locale| Some((locale.to_owned(), self.format(locale, key, None)?)))
+            .collect();
+
+        (!localizations.is_empty()).then_some(localizations)
+    }
+
+    fn format(&self, locale: &str, key: &str, args: Option<&FluentArgs<'_>>) -> Option<String> {
+        let bundle = self.bundles.get(locale)?;
+        let pattern = bundle.get_message(key)?.value()?;
+        let mut errors = Vec::new();
+        let value = bundle.format_pattern(pattern, args, &mut errors);
+
+        if !errors.is_empty() {
+            warn!(locale, key, ?errors, "Failed to format localization");
+        }
+
+        Some(value.into_owned())
+    }
+}
+
+/// Localize a message of the catalog, e.g. `t!(locale, "error-cooldown", timestamp = 42)`
+macro_rules! t {
+    ($locale:expr, $key:literal) => {
+        $crate::util::locale::LOCALES.get($locale, $key, None)
+    };
+    ($locale:expr, $key:literal, $( $arg:ident = $value:expr ),+ $(,)?) => {{
+        let mut args = fluent_bundle::FluentArgs::new();
+        $( args.set(stringify!($arg), $value); )+
+
+        $crate::util::locale::LOCALES.get($locale, $key, Some(&args))
+    }};
+}
+
+pub(crate) use t;
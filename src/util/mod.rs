@@ -1,5 +1,9 @@
 pub mod builder;
+pub mod cache;
 pub mod config;
 pub mod constants;
 pub mod ext;
+pub mod github;
 pub mod interaction;
+pub mod locale;
+pub mod secret;
@@ -0,0 +1,308 @@
+use std::{
+    cell::RefCell,
+    env,
+    fmt::{Debug, Formatter, Result as FmtResult},
+    fs,
+    path::{Path, PathBuf},
+    rc::Rc,
+};
+
+use ron::value::RawValue;
+use schemars::{gen::SchemaGenerator, schema::Schema, JsonSchema};
+use serde::{
+    de::{
+        value::{EnumAccessDeserializer, MapAccessDeserializer},
+        EnumAccess, Error as DeError, MapAccess, Visitor,
+    },
+    Deserialize, Deserializer, Serialize, Serializer,
+};
+
+use super::config::ConfigFormat;
+
+/// Directory that docker mounts its secrets into
+const DOCKER_SECRETS: &str = "/run/secrets";
+
+thread_local! {
+    static READER: RefCell<Option<Rc<SecretReader>>> = const { RefCell::new(None) };
+}
+
+/// A value such as a token that must not show up in logs.
+///
+/// In the config it can be given as a literal string, `env("VAR")`, `file("/path")`,
+/// or `credential("name")`; the latter is looked up in systemd's `$CREDENTIALS_DIRECTORY`
+/// and docker's `/run/secrets`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct Secret(String);
+
+impl Secret {
+    /// The actual value; never log it
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Debug for Secret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("Secret(<redacted>)")
+    }
+}
+
+impl Serialize for Secret {
+    fn serialize<S: Serializer>(&self, s: S) -> Result<S::Ok, S::Error> {
+        s.serialize_str("<redacted>")
+    }
+}
+
+impl<'de> Deserialize<'de> for Secret {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let reader = READER
+            .with(|reader| reader.borrow().clone())
+            .ok_or_else(|| DeError::custom("secrets must be read through a `SecretReader`"))?;
+
+        let source = if reader.format == ConfigFormat::Ron {
+            let raw = Box::<RawValue>::deserialize(d)?;
+
+            SecretSource::from_ron(raw.trim()).map_err(DeError::custom)?
+        } else {
+            d.deserialize_any(SecretVisitor)?
+        };
+
+        reader.resolve(source).map_err(DeError::custom)
+    }
+}
+
+/// Value of an environment variable by its name
+type EnvLookup = Box<dyn Fn(&str) -> Option<String>>;
+
+/// Resolves the secrets of a config while it's being deserialized
+pub struct SecretReader {
+    /// RON's `deserialize_any` drops the name of enum variants so `env("VAR")` can only be
+    /// read through `deserialize_enum`, which in turn rejects plain strings. Hence, RON
+    /// values are looked at before picking either.
+    format: ConfigFormat,
+    env: EnvLookup,
+    credential_dirs: Vec<PathBuf>,
+}
+
+impl SecretReader {
+    /// Read secrets of the given format from the process' environment, systemd's
+    /// `$CREDENTIALS_DIRECTORY`, and docker's `/run/secrets`
+    pub fn new(format: ConfigFormat) -> Self {
+        let credential_dirs = env::var_os("CREDENTIALS_DIRECTORY")
+            .map(PathBuf::from)
+            .into_iter()
+            .chain([PathBuf::from(DOCKER_SECRETS)])
+            .collect();
+
+        Self {
+            format,
+            env: Box::new(|var| env::var(var).ok()),
+            credential_dirs,
+        }
+    }
+
+    /// Look up `env("VAR")` secrets through the given function instead
+    pub fn env(mut self, env: impl Fn(&str) -> Option<String> + 'static) -> Self {
+        self.env = Box::new(env);
+
+        self
+    }
+
+    /// Look up `credential("name")` secrets in the given directory instead
+    pub fn credential_dir(mut self, dir: PathBuf) -> Self {
+        self.credential_dirs = vec![dir];
+
+        self
+    }
+
+    /// Deserialize secrets within `f` through this reader
+    pub fn read<T>(self, f: impl FnOnce() -> T) -> T {
+        struct Restore(Option<Rc<SecretReader>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                READER.with(|reader| *reader.borrow_mut() = self.0.take());
+            }
+        }
+
+        let prev = READER.with(|reader| reader.replace(Some(Rc::new(self))));
+        let _restore = Restore(prev);
+
+        f()
+    }
+
+    fn resolve(&self, source: SecretSource) -> Result<Secret, String> {
+        let value = match source {
+            SecretSource::Literal(value) => return Ok(Secret(value)),
+            SecretSource::Ref(SecretRef::Env(var)) => (self.env)(&var)
+                .ok_or_else(|| format!("Environment variable `{var}` is not set"))?,
+            SecretSource::Ref(SecretRef::File(path)) => read_secret(&path)?,
+            SecretSource::Ref(SecretRef::Credential(name)) => {
+                let path = self
+                    .credential_dirs
+                    .iter()
+                    .map(|dir| dir.join(&name))
+                    .find(|path| path.is_file())
+                    .ok_or_else(|| format!("Credential `{name}` was not found"))?;
+
+                read_secret(&path)?
+            }
+        };
+
+        Ok(Secret(value.trim().to_owned()))
+    }
+}
+
+struct SecretVisitor;
+
+impl<'de> Visitor<'de> for SecretVisitor {
+    type Value = SecretSource;
+
+    fn expecting(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("a string, `env`, `file`, or `credential`")
+    }
+
+    fn visit_str<E: DeError>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(SecretSource::Literal(v.to_owned()))
+    }
+
+    fn visit_string<E: DeError>(self, v: String) -> Result<Self::Value, E> {
+        Ok(SecretSource::Literal(v))
+    }
+
+    // e.g. `!env VAR` in YAML
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<Self::Value, A::Error> {
+        SecretRef::deserialize(EnumAccessDeserializer::new(data)).map(SecretSource::Ref)
+    }
+
+    // e.g. `{ "env": "VAR" }` in JSON or `{ env = "VAR" }` in TOML
+    fn visit_map<A: MapAccess<'de>>(self, map: A) -> Result<Self::Value, A::Error> {
+        SecretRef::deserialize(MapAccessDeserializer::new(map)).map(SecretSource::Ref)
+    }
+}
+
+impl JsonSchema for Secret {
+    fn schema_name() -> String {
+        "Secret".to_owned()
+    }
+
+    fn json_schema(gen: &mut SchemaGenerator) -> Schema {
+        SecretSource::json_schema(gen)
+    }
+}
+
+#[derive(JsonSchema)]
+#[serde(untagged)]
+enum SecretSource {
+    Literal(String),
+    Ref(SecretRef),
+}
+
+impl SecretSource {
+    fn from_ron(raw: &RawValue) -> ron::error::SpannedResult<Self> {
+        raw.into_rust()
+            .map(Self::Literal)
+            .or_else(|_| raw.into_rust().map(Self::Ref))
+    }
+}
+
+#[derive(Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+enum SecretRef {
+    Env(String),
+    File(PathBuf),
+    Credential(String),
+}
+
+fn read_secret(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Failed to read secret at {path:?}: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Deserialize)]
+    struct Secrets {
+        literal: Secret,
+        env: Secret,
+        file: Secret,
+        credential: Secret,
+    }
+
+    fn read_secrets<T>(format: ConfigFormat, dir: &Path, parse: impl FnOnce() -> T) -> T {
+        SecretReader::new(format)
+            .env(|var| (var == "ISSUE_BOT_TEST_TOKEN").then(|| "env-token".to_owned()))
+            .credential_dir(dir.to_owned())
+            .read(parse)
+    }
+
+    fn assert_secrets(secrets: Secrets) {
+        assert_eq!(secrets.literal.expose(), "literal-token");
+        assert_eq!(secrets.env.expose(), "env-token");
+        assert_eq!(secrets.file.expose(), "file-token");
+        assert_eq!(secrets.credential.expose(), "credential-token");
+    }
+
+    #[test]
+    fn parse_secret_sources() {
+        let dir = env::temp_dir().join(format!("issue-bot-secret-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        let file = dir.join("file_token");
+        fs::write(&file, "file-token\n").unwrap();
+        fs::write(dir.join("credential_token"), "credential-token\n").unwrap();
+
+        let file = file.to_str().unwrap().replace('\\', "\\\\");
+
+        let ron = format!(
+            r#"(
+                literal: "literal-token",
+                env: env("ISSUE_BOT_TEST_TOKEN"),
+                file: file("{file}"),
+                credential: credential("credential_token"),
+            )"#
+        );
+        assert_secrets(read_secrets(ConfigFormat::Ron, &dir, || ron::from_str(&ron)).unwrap());
+
+        let toml = format!(
+            r#"
+                literal = "literal-token"
+                env = {{ env = "ISSUE_BOT_TEST_TOKEN" }}
+                file = {{ file = "{file}" }}
+                credential = {{ credential = "credential_token" }}
+            "#
+        );
+        assert_secrets(read_secrets(ConfigFormat::Toml, &dir, || toml::from_str(&toml)).unwrap());
+
+        let json = format!(
+            r#"{{
+                "literal": "literal-token",
+                "env": {{ "env": "ISSUE_BOT_TEST_TOKEN" }},
+                "file": {{ "file": "{file}" }},
+                "credential": {{ "credential": "credential_token" }}
+            }}"#
+        );
+        let parse = || serde_json::from_str(&json);
+        assert_secrets(read_secrets(ConfigFormat::Json, &dir, parse).unwrap());
+
+        let yaml = format!(
+            r#"
+                literal: literal-token
+                env: {{ env: ISSUE_BOT_TEST_TOKEN }}
+                file: !file "{file}"
+                credential:
+                  credential: credential_token
+            "#
+        );
+        let parse = || serde_yaml::from_str(&yaml);
+        assert_secrets(read_secrets(ConfigFormat::Yaml, &dir, parse).unwrap());
+
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn secrets_need_reader() {
+        assert!(serde_json::from_str::<Secret>(r#""literal-token""#).is_err());
+    }
+}